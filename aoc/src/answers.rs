@@ -0,0 +1,209 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use common::Answer;
+use serde::{Deserialize, Serialize};
+
+use crate::solver::Part;
+
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct PartAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Answer>,
+}
+
+impl PartAnswers {
+    fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    fn set(&mut self, part: Part, answer: Answer) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+}
+
+/// How a computed answer compares to the stored one
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Status {
+    Pass,
+    Fail(Answer),
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail(expected) => write!(f, "FAIL, expected {expected}"),
+            Status::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    Serialize(PathBuf, toml::ser::Error),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io(path, err) => {
+                write!(f, "could not access answers `{}`: {err}", path.display())
+            }
+            AnswersError::Parse(path, err) => {
+                write!(f, "invalid answers `{}`: {err}", path.display())
+            }
+            AnswersError::Serialize(path, err) => {
+                write!(f, "could not write answers `{}`: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// Confirmed answers keyed by day, then input name, e.g.
+///
+/// ```toml
+/// [day-05.default]
+/// part1 = 35
+/// part2 = 46
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerStore(BTreeMap<String, BTreeMap<String, PartAnswers>>);
+
+/// `answers.toml` at the root of the workspace this runner was built from
+pub fn workspace_path() -> PathBuf {
+    crate::workspace_root().join("answers.toml")
+}
+
+fn day_key(day: u8) -> String {
+    format!("day-{day:02}")
+}
+
+impl AnswerStore {
+    /// Load the store at `path`, which is empty when the file does not exist yet
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents =
+            fs::read_to_string(path).map_err(|err| AnswersError::Io(path.to_path_buf(), err))?;
+
+        toml::from_str(&contents).map_err(|err| AnswersError::Parse(path.to_path_buf(), err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        let contents = toml::to_string_pretty(self)
+            .map_err(|err| AnswersError::Serialize(path.to_path_buf(), err))?;
+
+        fs::write(path, contents).map_err(|err| AnswersError::Io(path.to_path_buf(), err))
+    }
+
+    pub fn expected(&self, day: u8, input: &str, part: Part) -> Option<&Answer> {
+        self.0.get(&day_key(day))?.get(input)?.get(part)
+    }
+
+    pub fn record(&mut self, day: u8, input: &str, part: Part, answer: Answer) {
+        self.0
+            .entry(day_key(day))
+            .or_default()
+            .entry(input.to_string())
+            .or_default()
+            .set(part, answer);
+    }
+
+    pub fn check(&self, day: u8, input: &str, part: Part, answer: &Answer) -> Status {
+        match self.expected(day, input, part) {
+            // Compare textually, as the store cannot tell signed and unsigned answers apart
+            Some(expected) if expected.to_string() == answer.to_string() => Status::Pass,
+            Some(expected) => Status::Fail(expected.clone()),
+            None => Status::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use crate::answers::*;
+
+    #[test]
+    fn checking() {
+        let store: AnswerStore = toml::from_str(
+            "[day-06.default]
+            part1 = 288
+
+            [day-07.alice]
+            part2 = 5905",
+        )
+        .unwrap();
+
+        assert_eq!(
+            store.check(6, "default", Part::One, &Answer::Signed(288)),
+            Status::Pass
+        );
+        assert_eq!(
+            store.check(6, "default", Part::One, &Answer::Signed(289)),
+            Status::Fail(Answer::Unsigned(288))
+        );
+        assert_eq!(
+            store.check(6, "default", Part::Two, &Answer::Signed(71503)),
+            Status::Unknown
+        );
+        assert_eq!(
+            store.check(7, "default", Part::Two, &Answer::Unsigned(5905)),
+            Status::Unknown
+        );
+        assert_eq!(
+            store.check(7, "alice", Part::Two, &Answer::Unsigned(5905)),
+            Status::Pass
+        );
+    }
+
+    #[test]
+    fn recording() {
+        let path = env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut store = AnswerStore::load(&path).unwrap();
+        assert_eq!(store, AnswerStore::default());
+
+        store.record(5, "default", Part::One, Answer::Unsigned(35));
+        store.record(5, "default", Part::Two, Answer::Unsigned(46));
+        store.record(5, "default", Part::Two, Answer::Unsigned(47));
+        store.record(8, "bob", Part::One, Answer::Text("AAA".to_string()));
+        store.save(&path).unwrap();
+
+        let loaded = AnswerStore::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, store);
+        assert_eq!(
+            loaded.expected(5, "default", Part::Two),
+            Some(&Answer::Unsigned(47))
+        );
+        assert_eq!(
+            loaded.expected(8, "bob", Part::One),
+            Some(&Answer::Text("AAA".to_string()))
+        );
+    }
+}
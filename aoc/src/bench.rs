@@ -0,0 +1,318 @@
+use std::{
+    fmt::Display,
+    fs,
+    hint::black_box,
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::solver::Part;
+
+/// The separately timed steps of solving a day
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Stage {
+    fn from(value: Part) -> Self {
+        match value {
+            Part::One => Stage::Part1,
+            Part::Two => Stage::Part2,
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part 1"),
+            Stage::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Summary of the per-iteration times of every sample, in nanoseconds
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[f64]) -> Self {
+        let count = samples.len();
+        if count == 0 {
+            return Self {
+                samples: 0,
+                mean_ns: 0.0,
+                median_ns: 0.0,
+                stddev_ns: 0.0,
+            };
+        }
+
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count as f64;
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2.0
+        } else {
+            sorted[count / 2]
+        };
+
+        Self {
+            samples: count,
+            mean_ns: mean,
+            median_ns: median,
+            stddev_ns: variance.sqrt(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BenchConfig {
+    pub samples: usize,
+    pub warm_up_time: Duration,
+    /// Roughly how long all of the samples of a single stage should take
+    pub measurement_time: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            samples: 20,
+            warm_up_time: Duration::from_millis(250),
+            measurement_time: Duration::from_secs(1),
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Time `routine`, batching iterations into samples that fill the measurement time
+    ///
+    /// Every routine runs at least once during warm up and once per sample, so
+    /// slow routines will take longer than the configured times.
+    pub fn measure<T>(&self, mut routine: impl FnMut() -> T) -> Stats {
+        let warm_up_start = Instant::now();
+        let mut warm_up_iterations = 0u32;
+
+        while warm_up_iterations == 0 || warm_up_start.elapsed() < self.warm_up_time {
+            black_box(routine());
+            warm_up_iterations += 1;
+        }
+
+        let per_iteration = warm_up_start.elapsed().as_nanos() as f64 / warm_up_iterations as f64;
+        let per_sample = self.measurement_time.as_nanos() as f64 / self.samples.max(1) as f64;
+        let iterations = ((per_sample / per_iteration) as u64).max(1);
+
+        let samples = (0..self.samples)
+            .map(|_| {
+                let start = Instant::now();
+                for _ in 0..iterations {
+                    black_box(routine());
+                }
+                start.elapsed().as_nanos() as f64 / iterations as f64
+            })
+            .collect::<Vec<_>>();
+
+        Stats::from_samples(&samples)
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub input: String,
+    pub stage: Stage,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io(PathBuf, io::Error),
+    Json(PathBuf, serde_json::Error),
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BaselineError::Io(path, err) => {
+                write!(f, "could not access baseline `{}`: {err}", path.display())
+            }
+            BaselineError::Json(path, err) => {
+                write!(f, "invalid baseline `{}`: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for BaselineError {}
+
+/// Previously saved measurements to compare a benchmark run against
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub measurements: Vec<Measurement>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        let contents =
+            fs::read_to_string(path).map_err(|err| BaselineError::Io(path.to_path_buf(), err))?;
+
+        serde_json::from_str(&contents).map_err(|err| BaselineError::Json(path.to_path_buf(), err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|err| BaselineError::Json(path.to_path_buf(), err))?;
+
+        fs::write(path, contents).map_err(|err| BaselineError::Io(path.to_path_buf(), err))
+    }
+
+    pub fn find(&self, day: u8, input: &str, stage: Stage) -> Option<&Stats> {
+        self.measurements
+            .iter()
+            .find(|m| m.day == day && m.input == input && m.stage == stage)
+            .map(|m| &m.stats)
+    }
+}
+
+/// Relative change of the mean time against a baseline, e.g. `0.1` for 10% slower
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Change {
+    Improved(f64),
+    Regressed(f64),
+    Unchanged(f64),
+}
+
+impl Change {
+    pub fn new(current: &Stats, baseline: &Stats, threshold: f64) -> Self {
+        let change = (current.mean_ns - baseline.mean_ns) / baseline.mean_ns;
+
+        match change {
+            x if x > threshold => Change::Regressed(x),
+            x if x < -threshold => Change::Improved(x),
+            x => Change::Unchanged(x),
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Improved(x) => write!(f, "{:+.1}% improved", x * 100.0),
+            Change::Regressed(x) => write!(f, "{:+.1}% regressed", x * 100.0),
+            Change::Unchanged(x) => write!(f, "{:+.1}% no change", x * 100.0),
+        }
+    }
+}
+
+/// Format nanoseconds using the largest unit that keeps the value above one
+pub fn format_ns(ns: f64) -> String {
+    match ns {
+        x if x >= 1e9 => format!("{:.2}s", x / 1e9),
+        x if x >= 1e6 => format!("{:.2}ms", x / 1e6),
+        x if x >= 1e3 => format!("{:.2}µs", x / 1e3),
+        x => format!("{x:.0}ns"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use crate::bench::*;
+
+    #[test]
+    fn stats_from_samples() {
+        let stats = Stats::from_samples(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.mean_ns, 5.0);
+        assert_eq!(stats.median_ns, 4.5);
+        assert_eq!(stats.stddev_ns, 2.0);
+
+        let stats = Stats::from_samples(&[3.0, 1.0, 2.0]);
+        assert_eq!(stats.median_ns, 2.0);
+
+        assert_eq!(Stats::from_samples(&[]).samples, 0);
+    }
+
+    #[test]
+    fn measuring() {
+        let config = BenchConfig {
+            samples: 5,
+            warm_up_time: Duration::from_millis(1),
+            measurement_time: Duration::from_millis(5),
+        };
+        let mut calls = 0;
+        let stats = config.measure(|| calls += 1);
+
+        assert_eq!(stats.samples, 5);
+        assert!(calls >= 6);
+    }
+
+    #[test]
+    fn changes() {
+        let stats = |mean_ns| Stats {
+            samples: 1,
+            mean_ns,
+            median_ns: mean_ns,
+            stddev_ns: 0.0,
+        };
+
+        assert_eq!(
+            Change::new(&stats(120.0), &stats(100.0), 0.05),
+            Change::Regressed(0.2)
+        );
+        assert_eq!(
+            Change::new(&stats(50.0), &stats(100.0), 0.05),
+            Change::Improved(-0.5)
+        );
+        assert_eq!(
+            Change::new(&stats(102.0), &stats(100.0), 0.05),
+            Change::Unchanged(0.02)
+        );
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let path = env::temp_dir().join(format!("aoc-baseline-{}.json", std::process::id()));
+        let baseline = Baseline {
+            measurements: vec![Measurement {
+                day: 5,
+                input: "default".to_string(),
+                stage: Stage::Part2,
+                stats: Stats::from_samples(&[10.0, 20.0]),
+            }],
+        };
+
+        baseline.save(&path).unwrap();
+        let loaded = Baseline::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, baseline);
+        assert!(loaded.find(5, "default", Stage::Part2).is_some());
+        assert!(loaded.find(5, "default", Stage::Part1).is_none());
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(format_ns(512.0), "512ns");
+        assert_eq!(format_ns(1_500.0), "1.50µs");
+        assert_eq!(format_ns(2_250_000.0), "2.25ms");
+        assert_eq!(format_ns(3e9), "3.00s");
+    }
+}
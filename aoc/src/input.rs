@@ -0,0 +1,208 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Puzzle input loaded at runtime, along with a name identifying whose input it is
+#[derive(Clone, PartialEq, Debug)]
+pub struct NamedInput {
+    pub name: String,
+    pub contents: String,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    NoInputs(u8, PathBuf),
+    Io(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing(path) => {
+                write!(f, "input file `{}` does not exist", path.display())
+            }
+            InputError::NoInputs(day, path) => write!(
+                f,
+                "no `{}` inputs found in `{}`",
+                file_name(*day),
+                path.display()
+            ),
+            InputError::Io(path, err) => write!(f, "could not read `{}`: {err}", path.display()),
+            InputError::Stdin(err) => write!(f, "could not read stdin: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The file name used for a day's input, e.g. `day-05.txt`
+pub fn file_name(day: u8) -> String {
+    format!("day-{day:02}.txt")
+}
+
+/// Where the puzzle inputs for each day come from
+#[derive(Clone, PartialEq, Debug)]
+pub enum InputSource {
+    /// `day-XX/src/input.txt` within the given workspace root
+    Workspace(PathBuf),
+    /// A single file, used for every selected day
+    File(PathBuf),
+    /// Contents already read from stdin, used for every selected day
+    Stdin(String),
+    /// A directory of named inputs
+    ///
+    /// `<dir>/day-XX.txt` is named `default` and `<dir>/<name>/day-XX.txt` is named `<name>`
+    Directory(PathBuf),
+}
+
+impl InputSource {
+    /// The `day-XX/src/input.txt` files of the workspace this runner was built from
+    pub fn workspace() -> Self {
+        Self::Workspace(crate::workspace_root())
+    }
+
+    /// Build a source from a path argument, where `-` reads all of stdin
+    pub fn from_path(path: &Path) -> Result<Self, InputError> {
+        if path == Path::new("-") {
+            let mut contents = String::new();
+            io::stdin()
+                .read_to_string(&mut contents)
+                .map_err(InputError::Stdin)?;
+
+            return Ok(Self::Stdin(contents));
+        }
+
+        Ok(Self::File(path.to_path_buf()))
+    }
+
+    pub fn load(&self, day: u8) -> Result<Vec<NamedInput>, InputError> {
+        match self {
+            InputSource::Workspace(root) => {
+                let path = root
+                    .join(format!("day-{day:02}"))
+                    .join("src")
+                    .join("input.txt");
+
+                Ok(vec![read_named("default", &path)?])
+            }
+            InputSource::File(path) => Ok(vec![read_named(&path.display().to_string(), path)?]),
+            InputSource::Stdin(contents) => Ok(vec![NamedInput {
+                name: "stdin".to_string(),
+                contents: contents.clone(),
+            }]),
+            InputSource::Directory(dir) => load_directory(dir, day),
+        }
+    }
+}
+
+fn read_named(name: &str, path: &Path) -> Result<NamedInput, InputError> {
+    if !path.is_file() {
+        return Err(InputError::Missing(path.to_path_buf()));
+    }
+
+    let contents =
+        fs::read_to_string(path).map_err(|err| InputError::Io(path.to_path_buf(), err))?;
+
+    Ok(NamedInput {
+        name: name.to_string(),
+        contents,
+    })
+}
+
+fn load_directory(dir: &Path, day: u8) -> Result<Vec<NamedInput>, InputError> {
+    if !dir.is_dir() {
+        return Err(InputError::Missing(dir.to_path_buf()));
+    }
+
+    let file_name = file_name(day);
+    let mut inputs = Vec::new();
+
+    let default = dir.join(&file_name);
+    if default.is_file() {
+        inputs.push(read_named("default", &default)?);
+    }
+
+    let mut named_dirs = fs::read_dir(dir)
+        .map_err(|err| InputError::Io(dir.to_path_buf(), err))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.join(&file_name).is_file())
+        .collect::<Vec<_>>();
+    named_dirs.sort();
+
+    for named_dir in named_dirs {
+        let name = named_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        inputs.push(read_named(&name, &named_dir.join(&file_name))?);
+    }
+
+    if inputs.is_empty() {
+        return Err(InputError::NoInputs(day, dir.to_path_buf()));
+    }
+
+    Ok(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use crate::input::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn workspace_inputs_exist() {
+        let inputs = InputSource::workspace().load(1).unwrap();
+
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].name, "default");
+        assert!(!inputs[0].contents.is_empty());
+    }
+
+    #[test]
+    fn missing_file() {
+        let source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+
+        assert!(matches!(source.load(1), Err(InputError::Missing(_))));
+    }
+
+    #[test]
+    fn directory_of_named_inputs() {
+        let dir = scratch_dir("named");
+        fs::write(dir.join("day-05.txt"), "default input").unwrap();
+        fs::create_dir_all(dir.join("bob")).unwrap();
+        fs::write(dir.join("bob").join("day-05.txt"), "bob input").unwrap();
+        fs::create_dir_all(dir.join("alice")).unwrap();
+        fs::write(dir.join("alice").join("day-05.txt"), "alice input").unwrap();
+        fs::write(dir.join("alice").join("day-06.txt"), "alice day 6").unwrap();
+
+        let inputs = InputSource::Directory(dir.clone()).load(5).unwrap();
+        let names = inputs
+            .iter()
+            .map(|input| input.name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["default", "alice", "bob"]);
+        assert_eq!(inputs[2].contents, "bob input");
+
+        assert!(matches!(
+            InputSource::Directory(dir.clone()).load(7),
+            Err(InputError::NoInputs(7, _))
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
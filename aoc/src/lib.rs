@@ -0,0 +1,15 @@
+pub mod answers;
+pub mod bench;
+pub mod input;
+pub mod report;
+pub mod scaffold;
+pub mod selection;
+pub mod solver;
+
+use std::path::{Path, PathBuf};
+
+/// The root of the workspace this runner was built from
+pub fn workspace_root() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir).to_path_buf()
+}
@@ -0,0 +1,356 @@
+use std::{io, path::PathBuf, process::ExitCode, time::Duration};
+
+use aoc::{
+    answers::{self, AnswerStore, Status},
+    bench::{format_ns, Baseline, BenchConfig, Change, Measurement},
+    input::{InputError, InputSource, NamedInput},
+    report::{self, Format, Record},
+    scaffold::scaffold,
+    selection::{DayRange, Selection},
+    solver::{solvers, Part, Solver},
+};
+use clap::{ArgGroup, Args, Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers for one or more days
+    Run(RunArgs),
+    /// Time parsing and solving for one or more days
+    Bench(BenchArgs),
+    /// Generate a new day crate and register it with the runner
+    New(NewArgs),
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("selection").required(true).args(["day", "days", "all"])))]
+struct TargetArgs {
+    /// Run a single day
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Run an inclusive range of days, e.g. `3-6`
+    #[arg(long)]
+    days: Option<DayRange>,
+
+    /// Run every registered day
+    #[arg(short, long)]
+    all: bool,
+
+    /// Only run the given part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Read the input for every selected day from a file, or `-` for stdin
+    #[arg(short, long, conflicts_with = "inputs")]
+    input: Option<PathBuf>,
+
+    /// Read named inputs from `<DIR>/day-XX.txt` and `<DIR>/<NAME>/day-XX.txt`
+    #[arg(long, value_name = "DIR")]
+    inputs: Option<PathBuf>,
+}
+
+impl TargetArgs {
+    fn selection(&self) -> Selection {
+        match (self.day, &self.days) {
+            (Some(day), _) => Selection::Day(day),
+            (_, Some(range)) => Selection::Range(range.clone()),
+            _ => Selection::All,
+        }
+    }
+
+    fn input_source(&self) -> Result<InputSource, InputError> {
+        match (&self.input, &self.inputs) {
+            (Some(path), _) => InputSource::from_path(path),
+            (_, Some(dir)) => Ok(InputSource::Directory(dir.clone())),
+            _ => Ok(InputSource::workspace()),
+        }
+    }
+
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(1) => vec![Part::One],
+            Some(_) => vec![Part::Two],
+            None => Part::ALL.to_vec(),
+        }
+    }
+
+    /// Call `f` with every selected solver and each of its inputs
+    ///
+    /// Returns whether every selected day had a registered solver and loadable inputs.
+    fn for_each_input(&self, mut f: impl FnMut(&dyn Solver, &NamedInput)) -> bool {
+        let solvers = solvers();
+        let selected = self.selection().select(&solvers);
+
+        if selected.is_empty() {
+            eprintln!("No registered solver matches the selected days");
+            return false;
+        }
+
+        let source = match self.input_source() {
+            Ok(source) => source,
+            Err(err) => {
+                eprintln!("error: {err}");
+                return false;
+            }
+        };
+
+        let mut success = true;
+
+        for solver in selected {
+            match source.load(solver.day()) {
+                Ok(inputs) => inputs.iter().for_each(|input| f(solver, input)),
+                Err(err) => {
+                    eprintln!("error: day {:02}: {err}", solver.day());
+                    success = false;
+                }
+            }
+        }
+
+        success
+    }
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+
+    /// Check answers against this file, defaulting to the workspace's `answers.toml`
+    #[arg(long, value_name = "FILE")]
+    answers: Option<PathBuf>,
+
+    /// Save every computed answer to the answers file as confirmed
+    #[arg(long)]
+    record: bool,
+
+    /// How the results are printed
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+
+    /// Number of samples collected for each stage
+    #[arg(long, default_value_t = 20)]
+    samples: usize,
+
+    /// Milliseconds spent warming up each stage
+    #[arg(long, value_name = "MS", default_value_t = 250)]
+    warm_up: u64,
+
+    /// Milliseconds spent collecting the samples of each stage
+    #[arg(long, value_name = "MS", default_value_t = 1000)]
+    measurement_time: u64,
+
+    /// Compare the timings against a previously saved baseline
+    #[arg(long, value_name = "FILE")]
+    baseline: Option<PathBuf>,
+
+    /// Save the timings as a baseline for later comparisons
+    #[arg(long, value_name = "FILE")]
+    save_baseline: Option<PathBuf>,
+
+    /// Relative change of the mean time that counts as a regression or improvement
+    #[arg(long, default_value_t = 0.05)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to generate
+    #[arg(short, long)]
+    day: u8,
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let answers_path = args.answers.clone().unwrap_or_else(answers::workspace_path);
+    let mut answers = match AnswerStore::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parts = args.target.parts();
+    let text = args.format == Format::Text;
+    let mut records = Vec::new();
+    let mut success = true;
+
+    let loaded = args.target.for_each_input(|solver, input| {
+        let day = solver.day();
+        if text {
+            println!("Day {day:02} ({})", input.name);
+        }
+
+        let run = match solver.run(&input.contents, &parts) {
+            Ok(run) => run,
+            Err(err) => {
+                eprintln!("error: day {day:02} ({}): {err}", input.name);
+                records.extend(
+                    parts
+                        .iter()
+                        .map(|part| Record::failed(day, *part, &input.name, &err)),
+                );
+                success = false;
+                return;
+            }
+        };
+
+        for result in run.parts {
+            let (part, solution) = (result.part, result.answer);
+
+            let record = if args.record {
+                if text {
+                    println!("Part {part} Solution: {solution} [recorded]");
+                }
+                answers.record(day, &input.name, part, solution.clone());
+                Record::recorded(
+                    day,
+                    part,
+                    &input.name,
+                    solution,
+                    run.parse_time,
+                    result.solve_time,
+                )
+            } else {
+                let status = answers.check(day, &input.name, part, &solution);
+                if text {
+                    println!("Part {part} Solution: {solution} [{status}]");
+                }
+                success &= !matches!(status, Status::Fail(_));
+                Record::checked(
+                    day,
+                    part,
+                    &input.name,
+                    solution,
+                    status,
+                    run.parse_time,
+                    result.solve_time,
+                )
+            };
+
+            records.push(record);
+        }
+    });
+
+    if let Err(err) = report::write(args.format, &records, io::stdout().lock()) {
+        eprintln!("error: {err}");
+        success = false;
+    }
+
+    if args.record {
+        if let Err(err) = answers.save(&answers_path) {
+            eprintln!("error: {err}");
+            success = false;
+        }
+    }
+
+    if loaded && success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn bench(args: BenchArgs) -> ExitCode {
+    let config = BenchConfig {
+        samples: args.samples,
+        warm_up_time: Duration::from_millis(args.warm_up),
+        measurement_time: Duration::from_millis(args.measurement_time),
+    };
+
+    let baseline = match args.baseline.as_deref().map(Baseline::load).transpose() {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parts = args.target.parts();
+    let mut measurements = Vec::new();
+    let mut success = true;
+
+    let loaded = args.target.for_each_input(|solver, input| {
+        println!("Day {:02} ({})", solver.day(), input.name);
+
+        let timings = match solver.bench(&input.contents, &parts, &config) {
+            Ok(timings) => timings,
+            Err(err) => {
+                eprintln!("error: day {:02} ({}): {err}", solver.day(), input.name);
+                success = false;
+                return;
+            }
+        };
+
+        for (stage, stats) in timings {
+            let change = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.find(solver.day(), &input.name, stage))
+                .map(|previous| format!("  ({})", Change::new(&stats, previous, args.threshold)))
+                .unwrap_or_default();
+
+            println!(
+                "  {:<7} mean {:>10}  median {:>10}  stddev {:>10}{change}",
+                stage.to_string(),
+                format_ns(stats.mean_ns),
+                format_ns(stats.median_ns),
+                format_ns(stats.stddev_ns),
+            );
+
+            measurements.push(Measurement {
+                day: solver.day(),
+                input: input.name.clone(),
+                stage,
+                stats,
+            });
+        }
+    });
+
+    if let Some(path) = &args.save_baseline {
+        if let Err(err) = (Baseline { measurements }).save(path) {
+            eprintln!("error: {err}");
+            success = false;
+        }
+    }
+
+    if loaded && success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn new(args: NewArgs) -> ExitCode {
+    match scaffold(&aoc::workspace_root(), args.day) {
+        Ok(files) => {
+            for file in files {
+                println!("Wrote {}", file.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::New(args) => new(args),
+    }
+}
@@ -0,0 +1,242 @@
+use std::{fmt::Display, io, io::Write, time::Duration};
+
+use clap::ValueEnum;
+use common::Answer;
+use serde::Serialize;
+
+use crate::{answers::Status, solver::Part};
+
+/// How the results of a run are printed
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, ValueEnum)]
+pub enum Format {
+    /// Human readable lines, printed as each day finishes
+    #[default]
+    Text,
+    /// A JSON array of results, printed once every day has finished
+    Json,
+    /// CSV with a header row, printed once every day has finished
+    Csv,
+}
+
+/// The status of a single result, as written to machine-readable reports
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Pass,
+    Fail,
+    Unknown,
+    Recorded,
+    Error,
+}
+
+/// One row of a report, for a single part of a day solved from a named input
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: Option<Answer>,
+    pub expected: Option<Answer>,
+    pub status: Outcome,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+impl Record {
+    /// A part that was solved, compared against the answers store
+    pub fn checked(
+        day: u8,
+        part: Part,
+        input: &str,
+        answer: Answer,
+        status: Status,
+        parse_time: Duration,
+        solve_time: Duration,
+    ) -> Self {
+        let (status, expected) = match status {
+            Status::Pass => (Outcome::Pass, Some(answer.clone())),
+            Status::Fail(expected) => (Outcome::Fail, Some(expected)),
+            Status::Unknown => (Outcome::Unknown, None),
+        };
+
+        Self {
+            day,
+            part: part_number(part),
+            input: input.to_string(),
+            answer: Some(answer),
+            expected,
+            status,
+            parse_ns: Some(nanos(parse_time)),
+            solve_ns: Some(nanos(solve_time)),
+            error: None,
+        }
+    }
+
+    /// A part that was solved and saved to the answers store as confirmed
+    pub fn recorded(
+        day: u8,
+        part: Part,
+        input: &str,
+        answer: Answer,
+        parse_time: Duration,
+        solve_time: Duration,
+    ) -> Self {
+        Self {
+            status: Outcome::Recorded,
+            ..Self::checked(
+                day,
+                part,
+                input,
+                answer,
+                Status::Pass,
+                parse_time,
+                solve_time,
+            )
+        }
+    }
+
+    /// A part that could not be solved because its input was invalid
+    pub fn failed(day: u8, part: Part, input: &str, error: &impl Display) -> Self {
+        Self {
+            day,
+            part: part_number(part),
+            input: input.to_string(),
+            answer: None,
+            expected: None,
+            status: Outcome::Error,
+            parse_ns: None,
+            solve_ns: None,
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ReportError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    Csv(csv::Error),
+}
+
+impl Display for ReportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportError::Io(err) => write!(f, "could not write report: {err}"),
+            ReportError::Json(err) => write!(f, "could not write JSON report: {err}"),
+            ReportError::Csv(err) => write!(f, "could not write CSV report: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ReportError {}
+
+/// Write `records` to `writer` in a machine-readable `format`
+///
+/// Text output is printed while running instead, so nothing is written for it.
+pub fn write(
+    format: Format,
+    records: &[Record],
+    mut writer: impl Write,
+) -> Result<(), ReportError> {
+    match format {
+        Format::Text => Ok(()),
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut writer, records).map_err(ReportError::Json)?;
+            writeln!(writer).map_err(ReportError::Io)
+        }
+        Format::Csv => {
+            let mut csv = csv::Writer::from_writer(writer);
+            for record in records {
+                csv.serialize(record).map_err(ReportError::Csv)?;
+            }
+            csv.flush().map_err(ReportError::Io)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record::checked(
+                6,
+                Part::One,
+                "default",
+                Answer::Signed(288),
+                Status::Fail(Answer::Unsigned(289)),
+                Duration::from_micros(3),
+                Duration::from_nanos(250),
+            ),
+            Record::recorded(
+                6,
+                Part::Two,
+                "alice",
+                Answer::Signed(71503),
+                Duration::from_micros(3),
+                Duration::from_nanos(500),
+            ),
+            Record::failed(7, Part::One, "default", &"expected a hand, \"bid\""),
+        ]
+    }
+
+    fn render(format: Format) -> String {
+        let mut output = Vec::new();
+        write(format, &records(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn json_report() {
+        let report: serde_json::Value = serde_json::from_str(&render(Format::Json)).unwrap();
+
+        assert_eq!(
+            report[0],
+            serde_json::json!({
+                "day": 6,
+                "part": 1,
+                "input": "default",
+                "answer": 288,
+                "expected": 289,
+                "status": "fail",
+                "parse_ns": 3000,
+                "solve_ns": 250,
+                "error": null,
+            })
+        );
+        assert_eq!(report[1]["status"], "recorded");
+        assert_eq!(report[1]["expected"], 71503);
+        assert_eq!(report[2]["status"], "error");
+        assert_eq!(report[2]["answer"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn csv_report() {
+        assert_eq!(
+            render(Format::Csv),
+            "day,part,input,answer,expected,status,parse_ns,solve_ns,error
+6,1,default,288,289,fail,3000,250,
+6,2,alice,71503,71503,recorded,3000,500,
+7,1,default,,,error,,,\"expected a hand, \"\"bid\"\"\"
+"
+        );
+    }
+
+    #[test]
+    fn text_is_not_buffered() {
+        assert_eq!(render(Format::Text), "");
+    }
+}
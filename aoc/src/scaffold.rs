@@ -0,0 +1,221 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TOML_TEMPLATE: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_RS_TEMPLATE: &str = include_str!("../templates/lib.rs.template");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    InvalidDay(u8),
+    AlreadyExists(PathBuf),
+    Unregistrable(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay(day) => write!(f, "day {day} is not between 1 and 25"),
+            ScaffoldError::AlreadyExists(path) => {
+                write!(f, "`{}` already exists", path.display())
+            }
+            ScaffoldError::Unregistrable(path) => write!(
+                f,
+                "could not find where to register the new day in `{}`",
+                path.display()
+            ),
+            ScaffoldError::Io(path, err) => {
+                write!(f, "could not write `{}`: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{DAY}}", &format!("{day:02}"))
+        .replace("{{DAY_NUMBER}}", &day.to_string())
+}
+
+/// Insert `line` after the last line matching `is_anchor`
+fn insert_after_last(source: &str, line: &str, is_anchor: impl Fn(&str) -> bool) -> Option<String> {
+    let lines = source.lines().collect::<Vec<_>>();
+    let anchor = lines.iter().rposition(|line| is_anchor(line))?;
+
+    let mut updated = lines[..=anchor].to_vec();
+    updated.push(line);
+    updated.extend(&lines[anchor + 1..]);
+
+    Some(updated.join("\n") + "\n")
+}
+
+fn is_day_line(line: &str, prefix: &str) -> bool {
+    line.trim_start()
+        .strip_prefix(prefix)
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+/// Add the new day as a dependency of the runner
+fn add_dependency(manifest: &str, day: u8) -> Option<String> {
+    insert_after_last(
+        manifest,
+        &format!("day-{day:02} = {{ path = \"../day-{day:02}\" }}"),
+        |line| is_day_line(line, "day-"),
+    )
+}
+
+/// Add the new day to the list of solvers
+fn register_solver(solver_rs: &str, day: u8) -> Option<String> {
+    insert_after_last(
+        solver_rs,
+        &format!("        day::<day_{day:02}::Day{day:02}>(),"),
+        |line| is_day_line(line, "day::<day_"),
+    )
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    fs::write(path, contents).map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))
+}
+
+/// Generate `day-XX` within the workspace at `root` and register it with the runner
+///
+/// Returns every file that was created or modified.
+pub fn scaffold(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+
+    let crate_dir = root.join(format!("day-{day:02}"));
+    if crate_dir.exists() {
+        return Err(ScaffoldError::AlreadyExists(crate_dir));
+    }
+
+    // Prepare the registration first so nothing is written if it fails
+    let manifest_path = root.join("aoc").join("Cargo.toml");
+    let manifest = add_dependency(&read(&manifest_path)?, day)
+        .ok_or_else(|| ScaffoldError::Unregistrable(manifest_path.clone()))?;
+
+    let solver_path = root.join("aoc").join("src").join("solver.rs");
+    let solver_rs = register_solver(&read(&solver_path)?, day)
+        .ok_or_else(|| ScaffoldError::Unregistrable(solver_path.clone()))?;
+
+    let src_dir = crate_dir.join("src");
+    fs::create_dir_all(&src_dir).map_err(|err| ScaffoldError::Io(src_dir.clone(), err))?;
+
+    let files = [
+        (
+            crate_dir.join("Cargo.toml"),
+            render(CARGO_TOML_TEMPLATE, day),
+        ),
+        (src_dir.join("lib.rs"), render(LIB_RS_TEMPLATE, day)),
+        (src_dir.join("input.txt"), String::new()),
+        (manifest_path, manifest),
+        (solver_path, solver_rs),
+    ];
+
+    for (path, contents) in &files {
+        write(path, contents)?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use crate::scaffold::*;
+
+    #[test]
+    fn rendering() {
+        let lib_rs = render(LIB_RS_TEMPLATE, 9);
+
+        assert!(lib_rs.contains("pub struct Day09;"));
+        assert!(lib_rs.contains("const DAY: u8 = 9;"));
+        assert!(!lib_rs.contains("{{"));
+
+        let cargo_toml = render(CARGO_TOML_TEMPLATE, 12);
+        assert!(cargo_toml.contains("name = \"day-12\""));
+    }
+
+    #[test]
+    fn registration() {
+        let manifest = "[dependencies]
+common = { path = \"../common\" }
+day-01 = { path = \"../day-01\" }
+day-02 = { path = \"../day-02\" }
+serde = \"1\"
+";
+        assert_eq!(
+            add_dependency(manifest, 3).unwrap(),
+            "[dependencies]
+common = { path = \"../common\" }
+day-01 = { path = \"../day-01\" }
+day-02 = { path = \"../day-02\" }
+day-03 = { path = \"../day-03\" }
+serde = \"1\"
+"
+        );
+
+        let solver_rs = "    vec![
+        day::<day_01::Day01>(),
+    ]
+";
+        assert_eq!(
+            register_solver(solver_rs, 2).unwrap(),
+            "    vec![
+        day::<day_01::Day01>(),
+        day::<day_02::Day02>(),
+    ]
+"
+        );
+
+        assert!(register_solver("fn main() {}", 2).is_none());
+    }
+
+    #[test]
+    fn scaffolding_a_workspace() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::write(
+            root.join("aoc").join("Cargo.toml"),
+            "[dependencies]\nday-01 = { path = \"../day-01\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("solver.rs"),
+            include_str!("solver.rs"),
+        )
+        .unwrap();
+
+        let files = scaffold(&root, 9).unwrap();
+        assert_eq!(files.len(), 5);
+        assert!(root.join("day-09").join("src").join("lib.rs").is_file());
+        assert!(
+            fs::read_to_string(root.join("aoc").join("src").join("solver.rs"))
+                .unwrap()
+                .contains("        day::<day_09::Day09>(),\n")
+        );
+
+        assert!(matches!(
+            scaffold(&root, 9),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+        assert!(matches!(
+            scaffold(&root, 26),
+            Err(ScaffoldError::InvalidDay(26))
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
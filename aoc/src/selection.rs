@@ -0,0 +1,101 @@
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+use crate::solver::Solver;
+
+#[derive(Debug, PartialEq)]
+pub struct DayRangeParseError(String);
+
+impl Display for DayRangeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid day range `{}`, expected `START-END` or `START..=END`",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for DayRangeParseError {}
+
+/// An inclusive range of days, written as `3-6` or `3..=6`
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayRange(pub RangeInclusive<u8>);
+
+impl FromStr for DayRange {
+    type Err = DayRangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || DayRangeParseError(s.to_string());
+        let (start, end) = s
+            .split_once("..=")
+            .or_else(|| s.split_once('-'))
+            .ok_or_else(err)?;
+
+        let start = start.trim().parse::<u8>().map_err(|_| err())?;
+        let end = end.trim().parse::<u8>().map_err(|_| err())?;
+
+        if start > end {
+            return Err(err());
+        }
+
+        Ok(Self(start..=end))
+    }
+}
+
+/// Which of the registered days should be run
+#[derive(Clone, Debug, PartialEq)]
+pub enum Selection {
+    All,
+    Day(u8),
+    Range(DayRange),
+}
+
+impl Selection {
+    pub fn contains(&self, day: u8) -> bool {
+        match self {
+            Selection::All => true,
+            Selection::Day(x) => *x == day,
+            Selection::Range(DayRange(range)) => range.contains(&day),
+        }
+    }
+
+    pub fn select<'a>(&self, solvers: &'a [Box<dyn Solver>]) -> Vec<&'a dyn Solver> {
+        solvers
+            .iter()
+            .filter(|solver| self.contains(solver.day()))
+            .map(|solver| solver.as_ref())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{selection::*, solver::solvers};
+
+    #[test]
+    fn day_range_parsing() {
+        assert_eq!("3-6".parse(), Ok(DayRange(3..=6)));
+        assert_eq!("1..=8".parse(), Ok(DayRange(1..=8)));
+        assert_eq!("5-5".parse(), Ok(DayRange(5..=5)));
+        assert!("6-3".parse::<DayRange>().is_err());
+        assert!("3".parse::<DayRange>().is_err());
+        assert!("a-b".parse::<DayRange>().is_err());
+    }
+
+    #[test]
+    fn selecting_days() {
+        let solvers = solvers();
+        let days = |selection: Selection| {
+            selection
+                .select(&solvers)
+                .iter()
+                .map(|solver| solver.day())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(days(Selection::All).len(), solvers.len());
+        assert_eq!(days(Selection::Day(5)), vec![5]);
+        assert_eq!(days(Selection::Day(25)), Vec::<u8>::new());
+        assert_eq!(days(Selection::Range(DayRange(3..=5))), vec![3, 4, 5]);
+    }
+}
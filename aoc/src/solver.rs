@@ -0,0 +1,184 @@
+use std::{
+    fmt::Display,
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+use common::{Answer, ParseError, Solution};
+
+use crate::bench::{BenchConfig, Stage, Stats};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The answer to a single part and how long solving it took
+#[derive(Clone, PartialEq, Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub solve_time: Duration,
+}
+
+/// The answers of a run that parsed its input once for every part
+#[derive(Clone, PartialEq, Debug)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+/// A day's [`Solution`] with its input and answer types erased
+pub trait Solver {
+    fn day(&self) -> u8;
+
+    fn solve(&self, part: Part, input: &str) -> Result<Answer, ParseError>;
+
+    /// Parse `input` once and solve each of `parts` from it, timing every step
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run, ParseError>;
+
+    /// Time parsing and then solving each of `parts` from the parsed input
+    fn bench(
+        &self,
+        input: &str,
+        parts: &[Part],
+        config: &BenchConfig,
+    ) -> Result<Vec<(Stage, Stats)>, ParseError>;
+}
+
+pub struct Day<S>(PhantomData<S>);
+
+impl<S: Solution> Solver for Day<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve(&self, part: Part, input: &str) -> Result<Answer, ParseError> {
+        match part {
+            Part::One => S::solve_part1(input).map(Into::into),
+            Part::Two => S::solve_part2(input).map(Into::into),
+        }
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run, ParseError> {
+        let (parsed, parse_time) = timed(|| S::parse(input));
+        let parsed = parsed?;
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let (answer, solve_time) = match part {
+                    Part::One => timed(|| S::part1(&parsed).into()),
+                    Part::Two => timed(|| S::part2(&parsed).into()),
+                };
+
+                PartRun {
+                    part,
+                    answer,
+                    solve_time,
+                }
+            })
+            .collect();
+
+        Ok(Run { parse_time, parts })
+    }
+
+    fn bench(
+        &self,
+        input: &str,
+        parts: &[Part],
+        config: &BenchConfig,
+    ) -> Result<Vec<(Stage, Stats)>, ParseError> {
+        let parsed = S::parse(input)?;
+        let mut timings = vec![(Stage::Parse, config.measure(|| S::parse(input)))];
+
+        for part in parts {
+            let stats = match part {
+                Part::One => config.measure(|| S::part1(&parsed)),
+                Part::Two => config.measure(|| S::part2(&parsed)),
+            };
+            timings.push(((*part).into(), stats));
+        }
+
+        Ok(timings)
+    }
+}
+
+fn day<S: Solution + 'static>() -> Box<dyn Solver> {
+    Box::new(Day::<S>(PhantomData))
+}
+
+/// Every registered day, in order
+pub fn solvers() -> Vec<Box<dyn Solver>> {
+    vec![
+        day::<day_01::Day01>(),
+        day::<day_02::Day02>(),
+        day::<day_03::Day03>(),
+        day::<day_04::Day04>(),
+        day::<day_05::Day05>(),
+        day::<day_06::Day06>(),
+        day::<day_07::Day07>(),
+        day::<day_08::Day08>(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solver::*;
+
+    #[test]
+    fn solvers_are_ordered_and_unique() {
+        let days = solvers()
+            .iter()
+            .map(|solver| solver.day())
+            .collect::<Vec<_>>();
+
+        assert_eq!(days, (1..=8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn solver_dispatches_parts() {
+        let solvers = solvers();
+        let day_06 = &solvers[5];
+        let input = "Time:      7  15   30
+            Distance:  9  40  200";
+
+        assert_eq!(day_06.solve(Part::One, input), Ok(Answer::Signed(288)));
+        assert_eq!(day_06.solve(Part::Two, input), Ok(Answer::Signed(71503)));
+    }
+
+    #[test]
+    fn running_parts() {
+        let solvers = solvers();
+        let day_06 = &solvers[5];
+        let input = "Time:      7  15   30
+            Distance:  9  40  200";
+
+        let run = day_06.run(input, &[Part::Two]).unwrap();
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.parts[0].part, Part::Two);
+        assert_eq!(run.parts[0].answer, Answer::Signed(71503));
+
+        assert!(day_06.run("Time: 7", &Part::ALL).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// The answer to a single part of a puzzle, independent of the type a day solves with
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(x) => write!(f, "{x}"),
+            Answer::Signed(x) => write!(f, "{x}"),
+            Answer::Text(x) => write!(f, "{x}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($variant:ident, $target:ty, $($from:ty),+) => {
+        $(
+            impl From<$from> for Answer {
+                fn from(value: $from) -> Self {
+                    Self::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+impl_answer_from!(Unsigned, u64, u8, u16, u32, u64, usize);
+impl_answer_from!(Signed, i64, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::*;
+
+    #[test]
+    fn answer_conversions() {
+        assert_eq!(Answer::from(4361usize), Answer::Unsigned(4361));
+        assert_eq!(Answer::from(13u32), Answer::Unsigned(13));
+        assert_eq!(Answer::from(-288i64), Answer::Signed(-288));
+        assert_eq!(Answer::from("LRL"), Answer::Text("LRL".to_string()));
+    }
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::Unsigned(46).to_string(), "46");
+        assert_eq!(Answer::Signed(-71503).to_string(), "-71503");
+        assert_eq!(Answer::Text("AAA".to_string()).to_string(), "AAA");
+    }
+}
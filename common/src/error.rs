@@ -0,0 +1,174 @@
+use std::fmt::Display;
+
+use nom::IResult;
+
+/// A parse failure pointing at the offending location of the puzzle input
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    /// Byte offset into the source
+    pub offset: usize,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    /// The full line containing the error
+    pub snippet: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(source: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let offset = offset.min(source.len());
+        let line_start = source[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |idx| offset + idx);
+
+        Self {
+            offset,
+            line: source[..line_start].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            snippet: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Create an error where `rest`, a subslice of `source`, begins
+    pub fn at(source: &str, rest: &str, expected: impl Into<String>) -> Self {
+        Self::new(source, offset_of(source, rest), expected)
+    }
+
+    /// Relocate an error that was created against `fragment` to its position within `source`
+    pub fn within(self, source: &str, fragment: &str) -> Self {
+        Self::new(
+            source,
+            offset_of(source, fragment) + self.offset,
+            self.expected,
+        )
+    }
+}
+
+/// The byte offset of `rest` within `source`
+///
+/// Falls back to treating `rest` as a suffix when it does not point into `source`
+fn offset_of(source: &str, rest: &str) -> usize {
+    let start = source.as_ptr() as usize;
+    let position = rest.as_ptr() as usize;
+
+    if (start..=start + source.len()).contains(&position) {
+        position - start
+    } else {
+        source.len().saturating_sub(rest.len())
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "expected {} at line {}, column {}",
+            self.expected, self.line, self.column
+        )?;
+        writeln!(f, "  | {}", self.snippet)?;
+        write!(f, "  | {:>width$}", "^", width = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Convert nom results into a [`ParseError`] positioned within the original `source`
+pub trait ParseResultExt<'a, T> {
+    fn expected(self, source: &'a str, expected: &str) -> Result<(&'a str, T), ParseError>;
+
+    /// Like [`ParseResultExt::expected`], but also fail if anything but whitespace remains
+    fn complete(self, source: &'a str, expected: &str) -> Result<T, ParseError>;
+}
+
+impl<'a, T> ParseResultExt<'a, T> for IResult<&'a str, T> {
+    fn expected(self, source: &'a str, expected: &str) -> Result<(&'a str, T), ParseError> {
+        self.map_err(|err| match err {
+            nom::Err::Incomplete(_) => ParseError::new(source, source.len(), expected),
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                ParseError::at(source, err.input, expected)
+            }
+        })
+    }
+
+    fn complete(self, source: &'a str, expected: &str) -> Result<T, ParseError> {
+        let (rest, value) = self.expected(source, expected)?;
+        let rest = rest.trim_start();
+
+        if !rest.is_empty() {
+            return Err(ParseError::at(source, rest, expected));
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{bytes::complete::tag, character::complete};
+
+    use crate::error::*;
+
+    #[test]
+    fn positions() {
+        let source = "Card 1: 1 2\nCard 2: 3 4\nCrad 3: 5 6";
+        let err = ParseError::new(source, 24, "`Card ##:`");
+
+        assert_eq!(err.line, 3);
+        assert_eq!(err.column, 1);
+        assert_eq!(err.snippet, "Crad 3: 5 6");
+
+        let err = ParseError::at(source, &source[17..], "a number");
+        assert_eq!((err.line, err.column), (2, 6));
+    }
+
+    #[test]
+    fn relocation() {
+        let source = "Game 1: 3 blue\n  Game 2 3 red";
+        let line = source.lines().nth(1).unwrap().trim();
+        let err = ParseError::new(line, 6, "`:`").within(source, line);
+
+        assert_eq!((err.line, err.column), (2, 9));
+        assert_eq!(err.snippet, "  Game 2 3 red");
+    }
+
+    #[test]
+    fn nom_errors() {
+        let source = "Time: 7 15\nDistance: x";
+        let (rest, _) = tag::<_, _, nom::error::Error<_>>("Time: 7 15\nDistance: ")(source)
+            .expected(source, "`Time:`")
+            .unwrap();
+        let err = complete::u32::<_, nom::error::Error<_>>(rest)
+            .complete(source, "a distance")
+            .unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 11));
+        assert_eq!(
+            err.to_string(),
+            "expected a distance at line 2, column 11\n  | Distance: x\n  |           ^"
+        );
+
+        let err = tag::<_, _, nom::error::Error<_>>("Time:")("7 15")
+            .complete("7 15", "`Time:`")
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+
+    #[test]
+    fn trailing_input() {
+        let source = "1 2\n3 x";
+        let err = nom::multi::separated_list1(
+            complete::multispace1,
+            complete::u32::<_, nom::error::Error<_>>,
+        )(source)
+        .complete(source, "a number")
+        .unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
@@ -0,0 +1,8 @@
+pub mod answer;
+pub mod error;
+pub mod search;
+pub mod solution;
+
+pub use answer::Answer;
+pub use error::{ParseError, ParseResultExt};
+pub use solution::Solution;
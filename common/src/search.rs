@@ -0,0 +1,231 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// Anything that can be searched for a path between its nodes
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// The nodes reachable from `node` in a single step, each with the cost of that step
+    fn neighbors(&self, node: &Self::Node) -> impl Iterator<Item = (Self::Node, u64)>;
+}
+
+/// The nodes visited from the start to the goal, inclusive, and the total cost of the steps
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: u64,
+}
+
+impl<N> Path<N> {
+    pub fn steps(&self) -> usize {
+        self.nodes.len().saturating_sub(1)
+    }
+
+    pub fn goal(&self) -> &N {
+        self.nodes.last().expect("a path always contains its start")
+    }
+}
+
+/// Every node seen during a search, with the node it was first or most cheaply reached from
+struct Visited<N> {
+    indices: HashMap<N, usize>,
+    nodes: Vec<(N, Option<usize>, u64)>,
+}
+
+impl<N: Clone + Eq + Hash> Visited<N> {
+    fn new(start: N) -> Self {
+        Self {
+            indices: HashMap::from([(start.clone(), 0)]),
+            nodes: vec![(start, None, 0)],
+        }
+    }
+
+    /// Reach `node` from the node at `parent` with a total cost of `cost`
+    ///
+    /// Returns the index of `node` if this is the first or the cheapest way to reach it.
+    fn reach(&mut self, node: N, parent: usize, cost: u64) -> Option<usize> {
+        match self.indices.entry(node) {
+            Entry::Occupied(entry) => {
+                let idx = *entry.get();
+                if self.nodes[idx].2 <= cost {
+                    return None;
+                }
+
+                self.nodes[idx].1 = Some(parent);
+                self.nodes[idx].2 = cost;
+                Some(idx)
+            }
+            Entry::Vacant(entry) => {
+                let idx = self.nodes.len();
+                self.nodes.push((entry.key().clone(), Some(parent), cost));
+                entry.insert(idx);
+                Some(idx)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<N> {
+        let mut nodes = Vec::new();
+        let mut current = Some(goal);
+
+        while let Some(idx) = current {
+            nodes.push(self.nodes[idx].0.clone());
+            current = self.nodes[idx].1;
+        }
+        nodes.reverse();
+
+        Path {
+            nodes,
+            cost: self.nodes[goal].2,
+        }
+    }
+}
+
+/// Find the path from `start` with the fewest steps to a node matching `is_goal`
+///
+/// Step costs are ignored, so the cost of the path is its number of steps.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(idx) = queue.pop_front() {
+        let (node, _, steps) = &visited.nodes[idx];
+        if is_goal(node) {
+            return Some(visited.path(idx));
+        }
+
+        let (node, steps) = (node.clone(), steps + 1);
+        for (neighbor, _) in graph.neighbors(&node) {
+            if !visited.indices.contains_key(&neighbor) {
+                queue.extend(visited.reach(neighbor, idx, steps));
+            }
+        }
+    }
+
+    None
+}
+
+/// Find the cheapest path from `start` to a node matching `is_goal`
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    astar(graph, start, is_goal, |_| 0)
+}
+
+/// Find the cheapest path from `start` to a node matching `is_goal`, exploring
+/// the nodes with the lowest cost plus `heuristic` first
+///
+/// The path is only guaranteed to be the cheapest if `heuristic` never
+/// overestimates the remaining cost to the goal.
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    mut heuristic: impl FnMut(&G::Node) -> u64,
+) -> Option<Path<G::Node>> {
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut visited = Visited::new(start);
+
+    while let Some(Reverse((_, cost, idx))) = queue.pop() {
+        let (node, _, best) = &visited.nodes[idx];
+        // Skip entries that were queued before a cheaper way to the node was found
+        if cost > *best {
+            continue;
+        }
+
+        if is_goal(node) {
+            return Some(visited.path(idx));
+        }
+
+        let node = node.clone();
+        for (neighbor, step) in graph.neighbors(&node) {
+            let cost = cost + step;
+            let estimate = cost + heuristic(&neighbor);
+
+            if let Some(neighbor) = visited.reach(neighbor, idx, cost) {
+                queue.push(Reverse((estimate, cost, neighbor)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::*;
+
+    /// Weighted, directed edges between single letter nodes
+    struct Edges(Vec<(char, char, u64)>);
+
+    impl Graph for Edges {
+        type Node = char;
+
+        fn neighbors(&self, node: &char) -> impl Iterator<Item = (char, u64)> {
+            let node = *node;
+            self.0
+                .iter()
+                .filter(move |(from, _, _)| *from == node)
+                .map(|(_, to, cost)| (*to, *cost))
+        }
+    }
+
+    fn edges() -> Edges {
+        Edges(vec![
+            ('a', 'b', 7),
+            ('a', 'c', 1),
+            ('c', 'd', 2),
+            ('d', 'b', 1),
+            ('b', 'e', 1),
+            ('c', 'a', 1),
+            ('f', 'a', 1),
+        ])
+    }
+
+    #[test]
+    fn breadth_first() {
+        let path = bfs(&edges(), 'a', |node| *node == 'e').unwrap();
+
+        assert_eq!(path.nodes, vec!['a', 'b', 'e']);
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.steps(), 2);
+
+        assert_eq!(bfs(&edges(), 'a', |node| *node == 'f'), None);
+        assert_eq!(bfs(&edges(), 'a', |node| *node == 'a').unwrap().steps(), 0);
+    }
+
+    #[test]
+    fn cheapest() {
+        let path = dijkstra(&edges(), 'a', |node| *node == 'e').unwrap();
+
+        assert_eq!(path.nodes, vec!['a', 'c', 'd', 'b', 'e']);
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.goal(), &'e');
+
+        assert_eq!(dijkstra(&edges(), 'e', |node| *node == 'a'), None);
+    }
+
+    #[test]
+    fn guided() {
+        let remaining = |node: &char| match node {
+            'a' => 5,
+            'b' => 1,
+            'c' => 4,
+            'd' => 2,
+            _ => 0,
+        };
+        let path = astar(&edges(), 'a', |node| *node == 'e', remaining).unwrap();
+
+        assert_eq!(path.nodes, vec!['a', 'c', 'd', 'b', 'e']);
+        assert_eq!(path.cost, 5);
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{answer::Answer, error::ParseError};
+
+/// A day's puzzle, split into parsing the input once and solving each part from it
+pub trait Solution {
+    const DAY: u8;
+
+    /// The parsed puzzle input, which may borrow from the raw input
+    type Input<'a>;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1;
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2;
+
+    fn solve_part1(input: &str) -> Result<Self::Part1, ParseError> {
+        Ok(Self::part1(&Self::parse(input)?))
+    }
+
+    fn solve_part2(input: &str) -> Result<Self::Part2, ParseError> {
+        Ok(Self::part2(&Self::parse(input)?))
+    }
+}
@@ -0,0 +1,144 @@
+use std::{fmt::Display, io};
+
+use crate::{
+    matcher::Match,
+    vocabulary::{Findable, Vocabulary},
+};
+
+/// A word of the vocabulary found within a line, with the byte offsets it spans
+pub type Token<'v> = Match<'v, Findable>;
+
+/// What to do with lines that do not have any digits
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Strictness {
+    /// Fail the whole calibration
+    Strict,
+    /// Leave them out of the sum
+    #[default]
+    Lenient,
+}
+
+#[derive(Debug)]
+pub enum CalibrationError {
+    /// A line, counted from 1, without any words of the vocabulary
+    NoDigits { line: usize },
+    /// The input could not be read
+    Io(io::Error),
+}
+
+impl Display for CalibrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CalibrationError::NoDigits { line } => write!(f, "line {line} has no digits"),
+            CalibrationError::Io(err) => write!(f, "could not read calibration input: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for CalibrationError {}
+
+/// Everything the vocabulary found within a single line
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LineReport<'v> {
+    /// The line number, counted from 1
+    pub line: usize,
+    /// Every match, including overlapping ones, ordered by where they end
+    pub tokens: Vec<Token<'v>>,
+    /// The first and last digit, or `None` if the line has no digits
+    pub digits: Option<(Token<'v>, Token<'v>)>,
+    /// The two-digit value, or `None` if the line has no digits
+    pub value: Option<usize>,
+}
+
+/// The sum of the calibration values, along with a report for every line
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Calibration<'v> {
+    pub sum: u128,
+    pub lines: Vec<LineReport<'v>>,
+}
+
+impl<'v> Calibration<'v> {
+    /// The lines that were left out of the sum
+    pub fn without_digits(&self) -> impl Iterator<Item = &LineReport<'v>> {
+        self.lines.iter().filter(|report| report.digits.is_none())
+    }
+
+    /// The lines whose first and last digit are the same match
+    ///
+    /// Such lines may still have other matches within that one, like `seven`
+    /// within `seventeen` when both are part of the vocabulary.
+    pub fn single_digit(&self) -> impl Iterator<Item = &LineReport<'v>> {
+        self.lines
+            .iter()
+            .filter(|report| report.digits.is_some_and(|(first, last)| first == last))
+    }
+}
+
+impl<'v> LineReport<'v> {
+    pub fn new(line: usize, text: &str, vocabulary: &'v Vocabulary) -> Self {
+        let digits = vocabulary.first(text).zip(vocabulary.last(text));
+
+        Self {
+            line,
+            tokens: vocabulary.matches(text).collect(),
+            digits,
+            value: digits.map(|(first, last)| first.value.1 * 10 + last.value.1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::calibration::*;
+    use crate::vocabulary::AOCMode;
+
+    #[test]
+    fn line_reports() {
+        let vocabulary = Vocabulary::for_part(AOCMode::Part2);
+        let report = LineReport::new(3, "xtwone3", &vocabulary);
+
+        assert_eq!(report.line, 3);
+        assert_eq!(report.value, Some(23));
+        assert_eq!(
+            report
+                .tokens
+                .iter()
+                .map(|token| (token.start, &*token.value.0))
+                .collect::<Vec<_>>(),
+            vec![(1, "two"), (3, "one"), (6, "3")]
+        );
+
+        let report = LineReport::new(1, "abc", &vocabulary);
+        assert_eq!((report.tokens.len(), report.value), (0, None));
+    }
+
+    #[test]
+    fn single_digits() {
+        let vocabulary = Vocabulary::for_part(AOCMode::Part2).with([Findable(
+            "seventeen".into(),
+            7,
+            AOCMode::Part2,
+        )]);
+        let calibration = Calibration {
+            sum: 0,
+            lines: ["oneight", "seventeen", "x4y", "abc"]
+                .into_iter()
+                .enumerate()
+                .map(|(idx, text)| LineReport::new(idx + 1, text, &vocabulary))
+                .collect(),
+        };
+
+        // Two overlapping matches are two digits, while a match nested in
+        // another leaves the outer one as both the first and last digit
+        assert_eq!(calibration.lines[0].tokens.len(), 2);
+        assert_eq!(calibration.lines[0].value, Some(18));
+        assert_eq!(calibration.lines[1].tokens.len(), 2);
+        assert_eq!(
+            calibration
+                .single_digit()
+                .map(|report| report.line)
+                .collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use std::fmt::{self, Display};
+
+use crate::{
+    calibration::Token,
+    vocabulary::{AOCMode, Vocabulary},
+};
+
+/// How a line got its calibration value
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Explanation<'v, 'l> {
+    /// The line number, counted from 1
+    pub line: usize,
+    pub text: &'l str,
+    /// The first and last digit, which are the same match for lines with a
+    /// single digit, or `None` if the line has no digits
+    pub digits: Option<(Token<'v>, Token<'v>)>,
+}
+
+impl<'v, 'l> Explanation<'v, 'l> {
+    pub fn new(line: usize, text: &'l str, vocabulary: &'v Vocabulary) -> Self {
+        let digits = vocabulary.first(text).zip(vocabulary.last(text));
+
+        Self { line, text, digits }
+    }
+
+    /// The two-digit calibration value of the line
+    pub fn value(&self) -> Option<usize> {
+        self.digits
+            .map(|(first, last)| first.value.1 * 10 + last.value.1)
+    }
+}
+
+/// Explain every line of `input`
+pub fn explain<'v, 'l>(
+    input: &'l str,
+    vocabulary: &'v Vocabulary,
+) -> impl Iterator<Item = Explanation<'v, 'l>> + 'v
+where
+    'l: 'v,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, text)| Explanation::new(idx + 1, text, vocabulary))
+}
+
+fn describe(token: &Token) -> String {
+    let table = match token.value.2 {
+        AOCMode::Part1 => "part 1",
+        AOCMode::Part2 => "part 2",
+    };
+
+    format!(
+        "\"{}\" ({table}, bytes {}..{})",
+        token.value.0, token.start, token.end
+    )
+}
+
+impl<'v, 'l> Display for Explanation<'v, 'l> {
+    /// Show the line with its first and last digit underlined, followed by
+    /// where the value came from
+    ///
+    /// ```text
+    ///    4 | xtwone3four
+    ///      |  ^^^   ^^^^
+    ///      = 24 from "two" (part 2, bytes 1..4) and "four" (part 2, bytes 7..11)
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:>4} | {}", self.line, self.text)?;
+
+        let Some((first, last)) = self.digits else {
+            return writeln!(f, "     = no digits");
+        };
+
+        // Underline by character, so multi-byte characters before a match keep it aligned
+        let underline = self
+            .text
+            .char_indices()
+            .map(|(offset, _)| {
+                let within = |token: &Token| (token.start..token.end).contains(&offset);
+                if within(&first) || within(&last) {
+                    '^'
+                } else {
+                    ' '
+                }
+            })
+            .collect::<String>();
+        writeln!(f, "     | {}", underline.trim_end())?;
+
+        let value = first.value.1 * 10 + last.value.1;
+        if first == last {
+            writeln!(f, "     = {value} from {} alone", describe(&first))
+        } else {
+            writeln!(
+                f,
+                "     = {value} from {} and {}",
+                describe(&first),
+                describe(&last)
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::explain::*;
+
+    #[test]
+    fn explanations() {
+        let vocabulary = Vocabulary::for_part(AOCMode::Part2);
+        let explanations = explain("xtwone3four\ntwone\nabc", &vocabulary).collect::<Vec<_>>();
+
+        let (first, last) = explanations[0].digits.unwrap();
+        assert_eq!((first.start, first.end, &*first.value.0), (1, 4, "two"));
+        assert_eq!((last.start, last.end, &*last.value.0), (7, 11, "four"));
+        assert_eq!(explanations[0].value(), Some(24));
+        assert_eq!(explanations[1].value(), Some(21));
+        assert_eq!(explanations[2].value(), None);
+
+        let digits = Vocabulary::for_part(AOCMode::Part1);
+        let explanation = Explanation::new(7, "a5b", &digits);
+        assert_eq!(
+            explanation.digits.map(|(first, _)| first.value.2),
+            Some(AOCMode::Part1)
+        );
+    }
+
+    #[test]
+    fn pretty_printing() {
+        let vocabulary = Vocabulary::for_part(AOCMode::Part2);
+        let explanations = explain("xtwone3four\ntwone\nabc\nz7", &vocabulary)
+            .map(|explanation| explanation.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            explanations[0],
+            "   1 | xtwone3four\n     |  ^^^   ^^^^\n     = 24 from \"two\" (part 2, bytes 1..4) and \"four\" (part 2, bytes 7..11)\n"
+        );
+        assert_eq!(explanations[1].lines().nth(1), Some("     | ^^^^^"));
+        assert_eq!(explanations[2], "   3 | abc\n     = no digits\n");
+        assert_eq!(
+            explanations[3].lines().last(),
+            Some("     = 77 from \"7\" (part 1, bytes 1..2) alone")
+        );
+
+        let german = Vocabulary::parse("acht 8").unwrap();
+        let explanation = Explanation::new(1, "fünfacht", &german).to_string();
+        assert_eq!(explanation.lines().nth(1), Some("     |     ^^^^"));
+    }
+}
@@ -0,0 +1,215 @@
+pub mod calibration;
+pub mod explain;
+pub mod matcher;
+pub mod stream;
+pub mod vocabulary;
+
+use common::{ParseError, Solution};
+use once_cell::sync::Lazy;
+
+use crate::{
+    calibration::{Calibration, CalibrationError, LineReport, Strictness},
+    vocabulary::{AOCMode, Vocabulary},
+};
+
+static PART1_VOCABULARY: Lazy<Vocabulary> = Lazy::new(|| Vocabulary::for_part(AOCMode::Part1));
+static PART2_VOCABULARY: Lazy<Vocabulary> = Lazy::new(|| Vocabulary::for_part(AOCMode::Part2));
+
+/// The first digit of `line` followed by its last one, or `None` if it has no digits
+pub fn get_calibration_value(line: &str, vocabulary: &Vocabulary) -> Option<usize> {
+    let first_value = vocabulary.first(line)?.value.1;
+    let last_value = vocabulary.last(line)?.value.1;
+
+    Some((first_value * 10) + last_value)
+}
+
+/// Sum the calibration values of every line
+///
+/// Lines without any digits fail the calibration when `strictness` is
+/// [`Strictness::Strict`], and are left out of the sum otherwise. This is
+/// [`stream::get_sum_of_reader`] over the bytes of `input`.
+pub fn get_sum_of_input(
+    input: &str,
+    vocabulary: &Vocabulary,
+    strictness: Strictness,
+) -> Result<u128, CalibrationError> {
+    stream::get_sum_of_reader(input.as_bytes(), vocabulary, strictness)
+}
+
+/// Like [`get_sum_of_input`], but also report every word found in each line
+pub fn get_calibration_of_input<'v>(
+    input: &str,
+    vocabulary: &'v Vocabulary,
+    strictness: Strictness,
+) -> Result<Calibration<'v>, CalibrationError> {
+    let mut calibration = Calibration {
+        sum: 0,
+        lines: Vec::new(),
+    };
+
+    for (idx, line) in input.lines().enumerate() {
+        let report = LineReport::new(idx + 1, line, vocabulary);
+
+        match (report.value, strictness) {
+            (Some(value), _) => calibration.sum += value as u128,
+            (None, Strictness::Strict) => {
+                return Err(CalibrationError::NoDigits { line: report.line })
+            }
+            (None, Strictness::Lenient) => {}
+        }
+        calibration.lines.push(report);
+    }
+
+    Ok(calibration)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        let sum = get_sum_of_input(input, &PART1_VOCABULARY, Strictness::Lenient)
+            .expect("lenient calibration should not fail");
+
+        sum.try_into().expect("the sum should fit in a usize")
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        let sum = get_sum_of_input(input, &PART2_VOCABULARY, Strictness::Lenient)
+            .expect("lenient calibration should not fail");
+
+        sum.try_into().expect("the sum should fit in a usize")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calibration_value_part1() {
+        assert_eq!(get_calibration_value("1", &PART1_VOCABULARY), Some(11));
+        assert_eq!(get_calibration_value("1abc2", &PART1_VOCABULARY), Some(12));
+        assert_eq!(
+            get_calibration_value("pqr3stu8vwx", &PART1_VOCABULARY),
+            Some(38)
+        );
+        assert_eq!(
+            get_calibration_value("a1b2c3d4e5f", &PART1_VOCABULARY),
+            Some(15)
+        );
+        assert_eq!(
+            get_calibration_value("treb7uchet", &PART1_VOCABULARY),
+            Some(77)
+        );
+    }
+
+    #[test]
+    fn calibration_value_part2() {
+        assert_eq!(get_calibration_value("onetwo", &PART2_VOCABULARY), Some(12));
+        assert_eq!(get_calibration_value("twone", &PART2_VOCABULARY), Some(21));
+        assert_eq!(get_calibration_value("two1", &PART2_VOCABULARY), Some(21));
+    }
+
+    #[test]
+    fn test_input_sum() {
+        let input = "onetwo
+            twone";
+        assert_eq!(
+            get_sum_of_input(input, &PART2_VOCABULARY, Strictness::Strict).unwrap(),
+            33
+        );
+    }
+
+    #[test]
+    fn test_input_sum_matches_reader() {
+        let input = "two1nine\r\neightwothree\n\nzoneight234\n";
+
+        for strictness in [Strictness::Lenient, Strictness::Strict] {
+            let sum = get_sum_of_input(input, &PART2_VOCABULARY, strictness);
+            let streamed =
+                stream::get_sum_of_reader(input.as_bytes(), &PART2_VOCABULARY, strictness);
+            assert_eq!(
+                sum.map_err(|err| err.to_string()),
+                streamed.map_err(|err| err.to_string())
+            );
+        }
+        assert_eq!(
+            get_sum_of_input(input, &PART2_VOCABULARY, Strictness::Lenient).unwrap(),
+            29 + 83 + 14
+        );
+    }
+
+    #[test]
+    fn test_input_diagnostics() {
+        let input = "one
+            none at all
+            2x3";
+
+        assert!(matches!(
+            get_calibration_of_input(input, &PART1_VOCABULARY, Strictness::Strict),
+            Err(CalibrationError::NoDigits { line: 1 })
+        ));
+        assert!(matches!(
+            get_sum_of_input(input, &PART1_VOCABULARY, Strictness::Strict),
+            Err(CalibrationError::NoDigits { line: 1 })
+        ));
+        assert_eq!(
+            get_sum_of_input(input, &PART1_VOCABULARY, Strictness::Lenient).unwrap(),
+            23
+        );
+
+        let calibration =
+            get_calibration_of_input(input, &PART1_VOCABULARY, Strictness::Lenient).unwrap();
+        assert_eq!(calibration.sum, 23);
+        assert_eq!(
+            calibration
+                .without_digits()
+                .map(|report| report.line)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+
+        let calibration =
+            get_calibration_of_input(input, &PART2_VOCABULARY, Strictness::Lenient).unwrap();
+        assert_eq!(calibration.sum, 11 + 11 + 23);
+        assert_eq!(
+            calibration
+                .single_digit()
+                .map(|report| report.line)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(calibration.lines[1].tokens[0].start, 13);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = "1abc2
+            pqr3stu8vwx
+            a1b2c3d4e5f
+            treb7uchet";
+        assert_eq!(Day01::solve_part1(input), Ok(142));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "two1nine
+            eightwothree
+            abcone2threexyz
+            xtwone3four
+            4nineeightseven2
+            zoneight234
+            7pqrstsixteen";
+        assert_eq!(Day01::solve_part2(input), Ok(281));
+    }
+}
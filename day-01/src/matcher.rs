@@ -0,0 +1,260 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, VecDeque},
+    slice,
+};
+
+/// A state of the automaton, reached after reading the pattern prefix it stands for
+#[derive(Clone, Debug, Default)]
+struct Node {
+    children: BTreeMap<u8, usize>,
+    /// The state for the longest proper suffix of this prefix that is also a prefix
+    fail: usize,
+    /// The patterns ending here, including through `fail`, longest first
+    outputs: Vec<usize>,
+}
+
+/// A single-pass matcher for many patterns at once, in the style of Aho–Corasick
+///
+/// Each pattern carries a value that is handed back with its matches. Matches
+/// may overlap, so `twone` matches both `two` and `one`.
+#[derive(Clone, Debug)]
+pub struct Matcher<T> {
+    nodes: Vec<Node>,
+    patterns: Vec<(usize, T)>,
+    longest: usize,
+}
+
+/// A pattern found within a haystack, with the byte offsets it spans
+#[derive(PartialEq, Eq, Debug)]
+pub struct Match<'m, T> {
+    pub start: usize,
+    /// One past the last byte of the match
+    pub end: usize,
+    pub value: &'m T,
+}
+
+impl<'m, T> Clone for Match<'m, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'m, T> Copy for Match<'m, T> {}
+
+impl<'m, T> Match<'m, T> {
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+impl<T> Matcher<T> {
+    /// Build the automaton for every `(pattern, value)` pair, ignoring empty patterns
+    pub fn new<'p>(patterns: impl IntoIterator<Item = (&'p str, T)>) -> Self {
+        let mut nodes = vec![Node::default()];
+        let patterns = patterns
+            .into_iter()
+            .filter(|(pattern, _)| !pattern.is_empty())
+            .enumerate()
+            .map(|(idx, (pattern, value))| {
+                let mut state = 0;
+                for &byte in pattern.as_bytes() {
+                    state = match nodes[state].children.get(&byte) {
+                        Some(&child) => child,
+                        None => {
+                            nodes.push(Node::default());
+                            let child = nodes.len() - 1;
+                            nodes[state].children.insert(byte, child);
+                            child
+                        }
+                    };
+                }
+                nodes[state].outputs.push(idx);
+
+                (pattern.len(), value)
+            })
+            .collect::<Vec<_>>();
+
+        let mut matcher = Self {
+            nodes,
+            longest: patterns.iter().map(|(len, _)| *len).max().unwrap_or(0),
+            patterns,
+        };
+        matcher.link();
+        matcher
+    }
+
+    /// Set the failure links breadth first, so every shorter suffix is linked
+    /// before the states that fall back to it
+    fn link(&mut self) {
+        let mut queue = self.nodes[0]
+            .children
+            .values()
+            .copied()
+            .collect::<VecDeque<_>>();
+
+        while let Some(state) = queue.pop_front() {
+            let children = self.nodes[state]
+                .children
+                .iter()
+                .map(|(&byte, &child)| (byte, child))
+                .collect::<Vec<_>>();
+
+            for (byte, child) in children {
+                let fail = match state {
+                    0 => 0,
+                    _ => self.next(self.nodes[state].fail, byte),
+                };
+                let inherited = self.nodes[fail].outputs.clone();
+
+                self.nodes[child].fail = fail;
+                self.nodes[child].outputs.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+    }
+
+    /// The state after reading `byte` in `state`
+    fn next(&self, mut state: usize, byte: u8) -> usize {
+        loop {
+            if let Some(&child) = self.nodes[state].children.get(&byte) {
+                return child;
+            }
+            if state == 0 {
+                return 0;
+            }
+            state = self.nodes[state].fail;
+        }
+    }
+
+    /// Every match within `haystack`, including overlapping ones, ordered by
+    /// where they end and then from longest to shortest
+    pub fn find_iter<'m, 'h>(&'m self, haystack: &'h str) -> Matches<'m, 'h, T> {
+        Matches {
+            matcher: self,
+            haystack: haystack.as_bytes(),
+            pos: 0,
+            state: 0,
+            pending: [].iter(),
+        }
+    }
+
+    /// The match starting closest to the beginning of `haystack`, preferring
+    /// the longest of those starting at the same byte
+    pub fn first(&self, haystack: &str) -> Option<Match<'_, T>> {
+        let mut first: Option<Match<T>> = None;
+
+        for found in self.find_iter(haystack) {
+            if let Some(first) = first {
+                // Every later match ends at least here, so it cannot start any earlier
+                if found.end > first.start + self.longest {
+                    break;
+                }
+                if (found.start, Reverse(found.end)) >= (first.start, Reverse(first.end)) {
+                    continue;
+                }
+            }
+            first = Some(found);
+        }
+
+        first
+    }
+
+    /// The match ending closest to the end of `haystack`, preferring the
+    /// longest of those ending at the same byte
+    pub fn last(&self, haystack: &str) -> Option<Match<'_, T>> {
+        self.find_iter(haystack)
+            .max_by_key(|found| (found.end, Reverse(found.start)))
+    }
+}
+
+/// An iterator over the overlapping matches of a [`Matcher`]
+#[derive(Clone, Debug)]
+pub struct Matches<'m, 'h, T> {
+    matcher: &'m Matcher<T>,
+    haystack: &'h [u8],
+    /// The number of bytes read so far
+    pos: usize,
+    state: usize,
+    /// The patterns ending at `pos` that have not been reported yet
+    pending: slice::Iter<'m, usize>,
+}
+
+impl<'m, 'h, T> Iterator for Matches<'m, 'h, T> {
+    type Item = Match<'m, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(&idx) = self.pending.next() {
+                let (len, value) = &self.matcher.patterns[idx];
+                return Some(Match {
+                    start: self.pos - len,
+                    end: self.pos,
+                    value,
+                });
+            }
+
+            let &byte = self.haystack.get(self.pos)?;
+            self.state = self.matcher.next(self.state, byte);
+            self.pos += 1;
+            self.pending = self.matcher.nodes[self.state].outputs.iter();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matcher::*;
+
+    fn matcher() -> Matcher<u8> {
+        Matcher::new([("one", 1), ("two", 2), ("1", 1), ("ne", 0), ("", 9)])
+    }
+
+    fn spans(matcher: &Matcher<u8>, haystack: &str) -> Vec<(usize, usize, u8)> {
+        matcher
+            .find_iter(haystack)
+            .map(|found| (found.start, found.end, *found.value))
+            .collect()
+    }
+
+    #[test]
+    fn overlapping_matches() {
+        let matcher = matcher();
+
+        assert_eq!(
+            spans(&matcher, "twone1"),
+            vec![(0, 3, 2), (2, 5, 1), (3, 5, 0), (5, 6, 1)]
+        );
+        assert_eq!(spans(&matcher, "tw0 on"), vec![]);
+        assert_eq!(spans(&matcher, ""), vec![]);
+
+        let nested = Matcher::new([("abcd", 'a'), ("bc", 'b'), ("c", 'c')]);
+        assert_eq!(
+            nested
+                .find_iter("xabcd")
+                .map(|found| (found.start, *found.value))
+                .collect::<Vec<_>>(),
+            vec![(2, 'b'), (3, 'c'), (1, 'a')]
+        );
+    }
+
+    #[test]
+    fn first_and_last() {
+        let matcher = matcher();
+        let found = matcher.first("xtwone3").unwrap();
+
+        assert_eq!((found.start, found.end, *found.value), (1, 4, 2));
+        assert_eq!(found.len(), 3);
+        assert_eq!(matcher.last("xtwone3").map(|found| *found.value), Some(1));
+        assert_eq!(matcher.first("three"), None);
+
+        let nested = Matcher::new([("abcd", 'a'), ("bc", 'b'), ("c", 'c')]);
+        assert_eq!(nested.first("xabcd").map(|found| *found.value), Some('a'));
+        assert_eq!(nested.last("xabcd").map(|found| *found.value), Some('a'));
+        assert_eq!(nested.last("abcdbc").map(|found| *found.value), Some('b'));
+    }
+}
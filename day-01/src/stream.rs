@@ -0,0 +1,161 @@
+use std::io::BufRead;
+
+use rayon::prelude::*;
+
+use crate::{
+    calibration::{CalibrationError, Strictness},
+    get_calibration_value,
+    vocabulary::Vocabulary,
+};
+
+/// Read the next line into `buffer`, returning it without its line ending, or
+/// `None` at the end of the input
+fn read_line<'b>(
+    reader: &mut impl BufRead,
+    buffer: &'b mut String,
+) -> Result<Option<&'b str>, CalibrationError> {
+    buffer.clear();
+    if reader.read_line(buffer).map_err(CalibrationError::Io)? == 0 {
+        return Ok(None);
+    }
+
+    let line = buffer.strip_suffix('\n').unwrap_or(buffer);
+    Ok(Some(line.strip_suffix('\r').unwrap_or(line)))
+}
+
+/// The calibration value of a line, counted from 1, with lines without digits
+/// counting as 0 unless `strictness` is [`Strictness::Strict`]
+fn line_value(
+    line: usize,
+    text: &str,
+    vocabulary: &Vocabulary,
+    strictness: Strictness,
+) -> Result<u128, CalibrationError> {
+    match (get_calibration_value(text, vocabulary), strictness) {
+        (Some(value), _) => Ok(value as u128),
+        (None, Strictness::Strict) => Err(CalibrationError::NoDigits { line }),
+        (None, Strictness::Lenient) => Ok(0),
+    }
+}
+
+/// Sum the calibration values of every line of `reader`, one line at a time
+///
+/// Only a single line is held in memory. The sum is kept as a `u128`, which
+/// cannot overflow with values of at most 99 per line.
+pub fn get_sum_of_reader(
+    mut reader: impl BufRead,
+    vocabulary: &Vocabulary,
+    strictness: Strictness,
+) -> Result<u128, CalibrationError> {
+    let mut buffer = String::new();
+    let mut sum = 0;
+
+    for line in 1.. {
+        let Some(text) = read_line(&mut reader, &mut buffer)? else {
+            break;
+        };
+        sum += line_value(line, text, vocabulary, strictness)?;
+    }
+
+    Ok(sum)
+}
+
+/// Like [`get_sum_of_reader`], but read `chunk_lines` lines at a time and
+/// calibrate the lines of each chunk in parallel
+///
+/// In strict mode, the error is always for the earliest line without digits.
+pub fn get_sum_of_reader_parallel(
+    mut reader: impl BufRead,
+    vocabulary: &Vocabulary,
+    strictness: Strictness,
+    chunk_lines: usize,
+) -> Result<u128, CalibrationError> {
+    let chunk_lines = chunk_lines.max(1);
+    let mut chunk = Vec::with_capacity(chunk_lines);
+    let mut buffer = String::new();
+    let mut first_line = 1;
+    let mut sum = 0;
+
+    loop {
+        chunk.clear();
+        while chunk.len() < chunk_lines {
+            match read_line(&mut reader, &mut buffer)? {
+                Some(text) => chunk.push(text.to_string()),
+                None => break,
+            }
+        }
+        if chunk.is_empty() {
+            return Ok(sum);
+        }
+
+        let values = chunk
+            .par_iter()
+            .enumerate()
+            .map(|(idx, text)| line_value(first_line + idx, text, vocabulary, strictness))
+            .collect::<Vec<_>>();
+        for value in values {
+            sum += value?;
+        }
+
+        first_line += chunk.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::stream::*;
+    use crate::vocabulary::AOCMode;
+
+    const INPUT: &str = "two1nine
+eightwothree\r
+abcone2threexyz
+xtwone3four
+
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn streaming() {
+        let vocabulary = Vocabulary::for_part(AOCMode::Part2);
+
+        assert_eq!(
+            get_sum_of_reader(Cursor::new(INPUT), &vocabulary, Strictness::Lenient).unwrap(),
+            281
+        );
+        assert!(matches!(
+            get_sum_of_reader(Cursor::new(INPUT), &vocabulary, Strictness::Strict),
+            Err(CalibrationError::NoDigits { line: 5 })
+        ));
+
+        let invalid = Cursor::new(b"12\n\xff3\n".to_vec());
+        assert!(matches!(
+            get_sum_of_reader(invalid, &vocabulary, Strictness::Lenient),
+            Err(CalibrationError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn streaming_in_parallel() {
+        let vocabulary = Vocabulary::for_part(AOCMode::Part2);
+
+        for chunk_lines in [0, 1, 3, 100] {
+            let sum = get_sum_of_reader_parallel(
+                Cursor::new(INPUT),
+                &vocabulary,
+                Strictness::Lenient,
+                chunk_lines,
+            );
+            assert_eq!(sum.unwrap(), 281);
+        }
+
+        let input = format!("{INPUT}no digits\n{INPUT}");
+        assert!(matches!(
+            get_sum_of_reader_parallel(Cursor::new(input), &vocabulary, Strictness::Strict, 4),
+            Err(CalibrationError::NoDigits { line: 5 })
+        ));
+    }
+}
@@ -0,0 +1,284 @@
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use common::ParseError;
+
+use crate::matcher::{Match, Matcher, Matches};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AOCMode {
+    Part1,
+    Part2,
+}
+
+/// A word that stands for a digit, along with the table it came from
+///
+/// Digits written as digits belong to the [`AOCMode::Part1`] table, and
+/// spelled out ones to the [`AOCMode::Part2`] table. Built-in words are
+/// borrowed, while words read from a vocabulary file are owned.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Findable(pub Cow<'static, str>, pub usize, pub AOCMode);
+
+fn is_for_part1(findable: &Findable) -> bool {
+    findable.2 == AOCMode::Part1
+}
+
+const FINDABLES: [Findable; 18] = [
+    Findable(Cow::Borrowed("1"), 1, AOCMode::Part1),
+    Findable(Cow::Borrowed("one"), 1, AOCMode::Part2),
+    Findable(Cow::Borrowed("2"), 2, AOCMode::Part1),
+    Findable(Cow::Borrowed("two"), 2, AOCMode::Part2),
+    Findable(Cow::Borrowed("3"), 3, AOCMode::Part1),
+    Findable(Cow::Borrowed("three"), 3, AOCMode::Part2),
+    Findable(Cow::Borrowed("4"), 4, AOCMode::Part1),
+    Findable(Cow::Borrowed("four"), 4, AOCMode::Part2),
+    Findable(Cow::Borrowed("5"), 5, AOCMode::Part1),
+    Findable(Cow::Borrowed("five"), 5, AOCMode::Part2),
+    Findable(Cow::Borrowed("6"), 6, AOCMode::Part1),
+    Findable(Cow::Borrowed("six"), 6, AOCMode::Part2),
+    Findable(Cow::Borrowed("7"), 7, AOCMode::Part1),
+    Findable(Cow::Borrowed("seven"), 7, AOCMode::Part2),
+    Findable(Cow::Borrowed("8"), 8, AOCMode::Part1),
+    Findable(Cow::Borrowed("eight"), 8, AOCMode::Part2),
+    Findable(Cow::Borrowed("9"), 9, AOCMode::Part1),
+    Findable(Cow::Borrowed("nine"), 9, AOCMode::Part2),
+];
+
+#[derive(Debug)]
+pub enum VocabularyError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, ParseError),
+}
+
+impl Display for VocabularyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VocabularyError::Io(path, err) => {
+                write!(f, "could not read vocabulary `{}`: {err}", path.display())
+            }
+            VocabularyError::Parse(path, err) => {
+                write!(f, "invalid vocabulary `{}`: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for VocabularyError {}
+
+/// The words to look for in calibration lines
+///
+/// Words are matched exactly, so `One` is not the same as `one`.
+#[derive(Clone, Debug)]
+pub struct Vocabulary {
+    findables: Vec<Findable>,
+    matcher: Matcher<Findable>,
+}
+
+impl Vocabulary {
+    pub fn new(findables: impl IntoIterator<Item = Findable>) -> Self {
+        let findables = findables.into_iter().collect::<Vec<_>>();
+        let matcher = Matcher::new(
+            findables
+                .iter()
+                .map(|findable| (&*findable.0, findable.clone())),
+        );
+
+        Self { findables, matcher }
+    }
+
+    /// The original vocabulary of the specific AOC part, with only the digits
+    /// for part 1 and the English words from one to nine as well for part 2
+    pub fn for_part(aoc_mode: AOCMode) -> Self {
+        Self::new(
+            FINDABLES
+                .into_iter()
+                .filter(|findable| aoc_mode == AOCMode::Part2 || is_for_part1(findable)),
+        )
+    }
+
+    /// Read a vocabulary file, which has a word and the digit it stands for on
+    /// each line
+    ///
+    /// Blank lines and lines starting with `#` are ignored. Words made up of
+    /// digits count towards the part 1 table.
+    ///
+    /// ```text
+    /// # German
+    /// null 0
+    /// eins 1
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        let mut findables = Vec::new();
+
+        for line in source.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+            let word = tokens.next().expect("the line should not be blank");
+            let value = tokens
+                .next()
+                .ok_or_else(|| ParseError::at(source, &line[line.len()..], "a digit"))?;
+            if let Some(extra) = tokens.next() {
+                return Err(ParseError::at(source, extra, "the end of the line"));
+            }
+
+            let value = match value.as_bytes() {
+                [digit @ b'0'..=b'9'] => (digit - b'0') as usize,
+                _ => return Err(ParseError::at(source, value, "a digit from 0 to 9")),
+            };
+            let aoc_mode = match word.bytes().all(|byte| byte.is_ascii_digit()) {
+                true => AOCMode::Part1,
+                false => AOCMode::Part2,
+            };
+
+            findables.push(Findable(word.to_string().into(), value, aoc_mode));
+        }
+
+        Ok(Self::new(findables))
+    }
+
+    /// Read and [parse](Self::parse) the vocabulary file at `path`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, VocabularyError> {
+        let path = path.as_ref();
+        let source =
+            fs::read_to_string(path).map_err(|err| VocabularyError::Io(path.to_path_buf(), err))?;
+
+        Self::parse(&source).map_err(|err| VocabularyError::Parse(path.to_path_buf(), err))
+    }
+
+    /// Add more words, such as those of another vocabulary
+    pub fn with(self, findables: impl IntoIterator<Item = Findable>) -> Self {
+        Self::new(self.findables.into_iter().chain(findables))
+    }
+
+    pub fn findables(&self) -> &[Findable] {
+        &self.findables
+    }
+
+    /// Every word found within `line`, including overlapping ones
+    pub fn matches<'v, 'l>(&'v self, line: &'l str) -> Matches<'v, 'l, Findable> {
+        self.matcher.find_iter(line)
+    }
+
+    /// The word found closest to the beginning of `line`
+    pub fn first(&self, line: &str) -> Option<Match<'_, Findable>> {
+        self.matcher.first(line)
+    }
+
+    /// The word found closest to the end of `line`
+    pub fn last(&self, line: &str) -> Option<Match<'_, Findable>> {
+        self.matcher.last(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vocabulary::*;
+
+    fn value(vocabulary: &Vocabulary, line: &str) -> Option<usize> {
+        Some(vocabulary.first(line)?.value.1 * 10 + vocabulary.last(line)?.value.1)
+    }
+
+    #[test]
+    fn parsing() {
+        let vocabulary = Vocabulary::parse(
+            "# German
+            eins 1
+
+            zwei 2
+            10 1",
+        )
+        .unwrap();
+
+        assert_eq!(
+            vocabulary.findables(),
+            &[
+                Findable(Cow::Borrowed("eins"), 1, AOCMode::Part2),
+                Findable(Cow::Borrowed("zwei"), 2, AOCMode::Part2),
+                Findable(Cow::Borrowed("10"), 1, AOCMode::Part1)
+            ]
+        );
+
+        let err = Vocabulary::parse("eins 1\nzwei").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 5, "a digit")
+        );
+
+        let err = Vocabulary::parse("eins 10").unwrap_err();
+        assert_eq!(
+            (err.column, err.expected.as_str()),
+            (6, "a digit from 0 to 9")
+        );
+
+        let err = Vocabulary::parse("eins 1 2").unwrap_err();
+        assert_eq!(err.column, 8);
+    }
+
+    #[test]
+    fn languages() {
+        let german = Vocabulary::parse(include_str!("../vocabularies/german.txt")).unwrap();
+        let french = Vocabulary::parse(include_str!("../vocabularies/french.txt")).unwrap();
+        let spanish = Vocabulary::parse(include_str!("../vocabularies/spanish.txt")).unwrap();
+
+        assert_eq!(value(&german, "nullachtfünfzehn"), Some(5));
+        assert_eq!(value(&german, "achtundvierzig"), Some(84));
+        assert_eq!(value(&french, "zéro virgule sept"), Some(7));
+        assert_eq!(value(&spanish, "tresdoscuatro"), Some(34));
+        assert_eq!(value(&spanish, "3 dos"), Some(22));
+
+        let mixed = Vocabulary::for_part(AOCMode::Part1).with(spanish.findables().iter().cloned());
+        assert_eq!(value(&mixed, "3 dos"), Some(32));
+    }
+
+    #[test]
+    fn ordinals_and_numerals() {
+        let ordinals = Vocabulary::parse(include_str!("../vocabularies/ordinals.txt")).unwrap();
+        let roman = Vocabulary::parse(include_str!("../vocabularies/roman.txt")).unwrap();
+
+        assert_eq!(value(&ordinals, "the third and the first"), Some(31));
+        assert_eq!(value(&roman, "IV apples and IX pears"), Some(49));
+        assert_eq!(crate::get_calibration_value("IV", &roman), Some(44));
+        assert_eq!(crate::get_calibration_value("VIII", &roman), Some(88));
+        assert_eq!(value(&roman, "VI and III"), Some(63));
+    }
+
+    #[test]
+    fn loading() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("vocabularies");
+        let german = Vocabulary::load(dir.join("german.txt")).unwrap();
+
+        assert_eq!(
+            german.findables()[0],
+            Findable("null".into(), 0, AOCMode::Part2)
+        );
+        assert_eq!(value(&german, "neunhundertzwei"), Some(92));
+
+        assert!(matches!(
+            Vocabulary::load(dir.join("klingon.txt")),
+            Err(VocabularyError::Io(..))
+        ));
+
+        let path =
+            std::env::temp_dir().join(format!("day-01-vocabulary-{}.txt", std::process::id()));
+        fs::write(&path, "eins 1\nzwei").unwrap();
+        let loaded = Vocabulary::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        match loaded {
+            Err(VocabularyError::Parse(_, err)) => assert_eq!((err.line, err.column), (2, 5)),
+            _ => panic!("a word without a digit should not load"),
+        }
+
+        let english =
+            Vocabulary::for_part(AOCMode::Part2).with([Findable("zero".into(), 0, AOCMode::Part2)]);
+        assert_eq!(value(&english, "zero1"), Some(1));
+        assert_eq!(value(&english, "twozero"), Some(20));
+    }
+}
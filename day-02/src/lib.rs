@@ -0,0 +1,223 @@
+use common::{ParseError, Solution};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::cmp::max;
+
+static GAME_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"Game (\d*)").unwrap());
+
+fn capture_num(regex: &Lazy<Regex>, haystack: &str) -> Option<usize> {
+    let caps = regex.captures(haystack)?;
+    caps[1].parse::<usize>().ok()
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Handful(usize, usize, usize);
+
+impl Handful {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let mut handful = Self(0, 0, 0);
+
+        for cubes in input.split(',').map(str::trim) {
+            let expected_cubes = || ParseError::at(input, cubes, "`<n> red|green|blue`");
+            let (count, color) = cubes.split_once(' ').ok_or_else(expected_cubes)?;
+            let count = count.parse::<usize>().map_err(|_| expected_cubes())?;
+
+            match color {
+                "red" => handful.0 = count,
+                "green" => handful.1 = count,
+                "blue" => handful.2 = count,
+                _ => return Err(expected_cubes()),
+            }
+        }
+
+        Ok(handful)
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Game {
+    id: usize,
+    handfuls: Vec<Handful>,
+}
+
+impl Game {
+    fn new(desc: &str) -> Result<Self, ParseError> {
+        let expected_prefix = || ParseError::at(desc, desc.trim_start(), "`Game ##:`");
+        let (game, handful_strs) = desc.split_once(':').ok_or_else(expected_prefix)?;
+
+        Ok(Self {
+            id: capture_num(&GAME_REGEX, game).ok_or_else(expected_prefix)?,
+            handfuls: handful_strs
+                .split(';')
+                .map(|handful| Handful::new(handful).map_err(|err| err.within(desc, handful)))
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+
+    fn minimum_cubes(&self) -> (usize, usize, usize) {
+        self.handfuls.iter().fold((0, 0, 0), |acc, handful| {
+            (
+                max(acc.0, handful.0),
+                max(acc.1, handful.1),
+                max(acc.2, handful.2),
+            )
+        })
+    }
+
+    fn is_within_max(&self, r: usize, g: usize, b: usize) -> bool {
+        let min = self.minimum_cubes();
+
+        min.0 <= r && min.1 <= g && min.2 <= b
+    }
+
+    fn power_of_min_set(&self) -> usize {
+        let min = self.minimum_cubes();
+
+        min.0 * min.1 * min.2
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .map(|line| Game::new(line).map_err(|err| err.within(input, line)))
+            .collect()
+    }
+
+    fn part1(games: &Self::Input<'_>) -> Self::Part1 {
+        games
+            .iter()
+            .filter(|game| game.is_within_max(12, 13, 14))
+            .map(|game| game.id)
+            .sum()
+    }
+
+    fn part2(games: &Self::Input<'_>) -> Self::Part2 {
+        games.iter().map(Game::power_of_min_set).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handful_parsing() {
+        assert_eq!(Handful::new("3 blue, 4 red"), Ok(Handful(4, 0, 3)));
+        assert_eq!(Handful::new("1 red, 2 green, 6 blue"), Ok(Handful(1, 2, 6)));
+        assert_eq!(Handful::new("2 green"), Ok(Handful(0, 2, 0)));
+        assert_eq!(Handful::new("1 blue, 2 green"), Ok(Handful(0, 2, 1)));
+        assert_eq!(Handful::new("3 green, 4 blue, 1 red"), Ok(Handful(1, 3, 4)));
+    }
+
+    #[test]
+    fn game_parsing() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let handfuls = vec![Handful(4, 0, 3), Handful(1, 2, 6), Handful(0, 2, 0)];
+        assert_eq!(Game::new(input), Ok(Game { id: 1, handfuls }));
+
+        let input = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
+        let handfuls = vec![Handful(0, 2, 1), Handful(1, 3, 4), Handful(0, 1, 1)];
+        assert_eq!(Game::new(input), Ok(Game { id: 2, handfuls }));
+    }
+
+    #[test]
+    fn game_parse_errors() {
+        let input = "Game 1: 3 blue, 4 red
+            Gme 2: 1 blue, 2 green
+            Game 3 8 green";
+        let err = Day02::parse(input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 13));
+        assert_eq!(err.expected, "`Game ##:`");
+    }
+
+    #[test]
+    fn handful_parse_errors() {
+        let input = "Game 1: 3 blue, 4 red; 3 purple
+            Game 2: 1 blue";
+        let err = Day02::parse(input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (1, 24));
+        assert_eq!(err.expected, "`<n> red|green|blue`");
+
+        let input = "Game 1: 3 blue
+            Game 2: 1 blue, green; 2 red";
+        let err = Day02::parse(input).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 29));
+
+        assert!(Day02::parse("Game 1: x blue").is_err());
+        assert!(Day02::parse("Game 1: 3 bleu").is_err());
+    }
+
+    fn _make_games() -> [Game; 5] {
+        [
+            Game::new("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap(),
+            Game::new("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue").unwrap(),
+            Game::new("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red")
+                .unwrap(),
+            Game::new("Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red")
+                .unwrap(),
+            Game::new("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green").unwrap(),
+        ]
+    }
+
+    #[test]
+    fn game_min_cubes() {
+        let games = _make_games();
+        assert_eq!(games[0].minimum_cubes(), (4, 2, 6));
+        assert_eq!(games[1].minimum_cubes(), (1, 3, 4));
+        assert_eq!(games[2].minimum_cubes(), (20, 13, 6));
+        assert_eq!(games[3].minimum_cubes(), (14, 3, 15));
+        assert_eq!(games[4].minimum_cubes(), (6, 3, 2));
+    }
+
+    #[test]
+    fn game_within_max() {
+        let games = _make_games();
+        assert!(games[0].is_within_max(12, 13, 14));
+        assert!(games[1].is_within_max(12, 13, 14));
+        assert!(!games[2].is_within_max(12, 13, 14));
+        assert!(!games[3].is_within_max(12, 13, 14));
+        assert!(games[4].is_within_max(12, 13, 14));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+            Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+            Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+            Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        assert_eq!(Day02::solve_part1(input), Ok(8));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+            Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+            Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+            Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        assert_eq!(Day02::solve_part2(input), Ok(2286));
+    }
+
+    #[test]
+    fn game_min_powers() {
+        let games = _make_games();
+        assert_eq!(games[0].power_of_min_set(), 48);
+        assert_eq!(games[1].power_of_min_set(), 12);
+        assert_eq!(games[2].power_of_min_set(), 1560);
+        assert_eq!(games[3].power_of_min_set(), 630);
+        assert_eq!(games[4].power_of_min_set(), 36);
+    }
+}
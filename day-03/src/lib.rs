@@ -1,3 +1,117 @@
-pub mod cell;
-pub mod grid;
-pub mod neighborhood;
+pub mod part_number;
+pub mod schematic;
+
+use common::{ParseError, Solution};
+use grid::{Grid, GridError};
+
+use crate::schematic::Schematic;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Schematic;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let grid = Grid::parse(input).map_err(|err| match err {
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => {
+                // Point at where the row should have ended, or the first extra character
+                let line = input.trim().lines().nth(row).unwrap_or_default().trim();
+                let end = line
+                    .char_indices()
+                    .nth(expected.min(found))
+                    .map_or(line.len(), |(idx, _)| idx);
+
+                ParseError::at(
+                    input,
+                    &line[end..],
+                    format!("a row of {expected} characters"),
+                )
+            }
+            err => ParseError::new(input, 0, err.to_string()),
+        })?;
+
+        Ok(Schematic::from(grid))
+    }
+
+    fn part1(schematic: &Self::Input<'_>) -> Self::Part1 {
+        schematic
+            .engine_parts()
+            .map(|part_number| part_number.value)
+            .sum::<usize>()
+    }
+
+    fn part2(schematic: &Self::Input<'_>) -> Self::Part2 {
+        schematic
+            .gears(2)
+            .map(|(_, parts)| {
+                parts
+                    .iter()
+                    .map(|part_number| part_number.value)
+                    .product::<usize>()
+            })
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let schematic = "467..114..
+            ...*.....
+            ..35..633.";
+        let err = Day03::parse(schematic).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 22));
+        assert_eq!(err.expected, "a row of 10 characters");
+
+        let schematic = "467..
+            ...*..";
+        let err = Day03::parse(schematic).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 18));
+
+        assert_eq!(Day03::solve_part1(""), Ok(0));
+        assert_eq!(Day03::solve_part2("\n"), Ok(0));
+    }
+
+    #[test]
+    fn test_part1() {
+        let schematic = "467..114..
+            ...*......
+            ..35..633.
+            ......#...
+            617*......
+            .....+.58.
+            ..592.....
+            ......755.
+            ...$.*....
+            .664.598..";
+        assert_eq!(Day03::solve_part1(schematic), Ok(4361));
+    }
+
+    #[test]
+    fn test_part2() {
+        let schematic = "467..114..
+            ...*......
+            ..35..633.
+            ......#...
+            617*......
+            .....+.58.
+            ..592.....
+            ......755.
+            ...$.*....
+            .664.598..";
+        assert_eq!(Day03::solve_part2(schematic), Ok(467835));
+    }
+}
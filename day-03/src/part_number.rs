@@ -0,0 +1,193 @@
+use grid::{Grid, Kind, Region, Regions};
+
+/// Digits only continue a number along the same row
+const DIGIT_RUN: Kind<'static> = Kind::Stencil(&[(0, -1), (0, 1)]);
+
+/// A number in the schematic, labeled by the run of digits it was read from
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct PartNumber {
+    pub label: usize,
+    pub row_idx: usize,
+    /// The column of the first digit
+    pub start_col: usize,
+    /// The column of the last digit, inclusive
+    pub end_col: usize,
+    pub value: usize,
+}
+
+impl PartNumber {
+    fn new(grid: &Grid<char>, region: &Region) -> Self {
+        let value = region
+            .values(grid)
+            .filter_map(|c| c.to_digit(10))
+            .fold(0, |sum, digit| (sum * 10) + (digit as usize));
+
+        Self {
+            label: region.label,
+            row_idx: region.bounds.top,
+            start_col: region.bounds.left,
+            end_col: region.bounds.right,
+            value,
+        }
+    }
+
+    /// The number of digits, which is always at least one
+    pub fn digits(&self) -> usize {
+        self.end_col - self.start_col + 1
+    }
+
+    /// The `(row, column)` positions of the digits, from left to right
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> {
+        let row_idx = self.row_idx;
+        (self.start_col..=self.end_col).map(move |col_idx| (row_idx, col_idx))
+    }
+
+    /// The `(row, column)` positions surrounding the digits within `grid`, in row-major order
+    pub fn neighbor_ring<T>(&self, grid: &Grid<T>) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (grid.width(), grid.height());
+        let left = self.start_col.checked_sub(1);
+        let right = Some(self.end_col + 1).filter(|col_idx| *col_idx < width);
+        let (first_col, last_col) = (
+            left.unwrap_or(self.start_col),
+            right.unwrap_or(self.end_col),
+        );
+
+        let above = self.row_idx.checked_sub(1);
+        let below = Some(self.row_idx + 1).filter(|row_idx| *row_idx < height);
+        let row = |row_idx: Option<usize>| {
+            row_idx.into_iter().flat_map(move |row_idx| {
+                (first_col..=last_col).map(move |col_idx| (row_idx, col_idx))
+            })
+        };
+
+        let row_idx = self.row_idx;
+        row(above)
+            .chain(left.map(|col_idx| (row_idx, col_idx)))
+            .chain(right.map(|col_idx| (row_idx, col_idx)))
+            .chain(row(below))
+    }
+}
+
+/// Every number in a schematic, found as runs of digits
+#[derive(Clone, Debug)]
+pub struct PartNumbers {
+    regions: Regions,
+    part_numbers: Vec<PartNumber>,
+}
+
+impl PartNumbers {
+    pub fn new(grid: &Grid<char>) -> Self {
+        let regions = grid.regions(DIGIT_RUN, char::is_ascii_digit);
+        let part_numbers = regions
+            .iter()
+            .map(|region| PartNumber::new(grid, region))
+            .collect();
+
+        Self {
+            regions,
+            part_numbers,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.part_numbers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.part_numbers.is_empty()
+    }
+
+    pub fn get(&self, label: usize) -> PartNumber {
+        self.part_numbers[label]
+    }
+
+    /// Every number, from top to bottom and left to right
+    pub fn iter(&self) -> impl Iterator<Item = PartNumber> + '_ {
+        self.part_numbers.iter().copied()
+    }
+
+    /// The number that the digit at the given position is a part of
+    pub fn at(&self, row_idx: usize, col_idx: usize) -> Option<PartNumber> {
+        let label = self.regions.label(row_idx, col_idx)?;
+
+        Some(self.get(label))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::part_number::*;
+
+    #[test]
+    fn digit_runs() {
+        let grid = Grid::parse(
+            "467..1
+            .3*..2
+            ..35.3",
+        )
+        .unwrap();
+        let part_numbers = PartNumbers::new(&grid);
+
+        assert_eq!(
+            part_numbers
+                .iter()
+                .map(|part_number| part_number.value)
+                .collect::<Vec<_>>(),
+            vec![467, 1, 3, 2, 35, 3]
+        );
+        assert_eq!(part_numbers.at(0, 1).map(|part| part.value), Some(467));
+        assert_eq!(part_numbers.at(2, 3), part_numbers.at(2, 2));
+        assert_eq!(part_numbers.at(1, 2), None);
+    }
+
+    #[test]
+    fn spans() {
+        let grid = Grid::parse(
+            ".....
+            .123.
+            .....
+            45...",
+        )
+        .unwrap();
+        let part_numbers = PartNumbers::new(&grid);
+        let middle = part_numbers.get(0);
+
+        assert_eq!(
+            (
+                middle.row_idx,
+                middle.start_col,
+                middle.end_col,
+                middle.digits()
+            ),
+            (1, 1, 3, 3)
+        );
+        assert_eq!(
+            middle.cells().collect::<Vec<_>>(),
+            vec![(1, 1), (1, 2), (1, 3)]
+        );
+        assert_eq!(
+            middle.neighbor_ring(&grid).collect::<Vec<_>>(),
+            vec![
+                (0, 0),
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (0, 4),
+                (1, 0),
+                (1, 4),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+                (2, 3),
+                (2, 4)
+            ]
+        );
+
+        let corner = part_numbers.get(1);
+        assert_eq!(corner.value, 45);
+        assert_eq!(
+            corner.neighbor_ring(&grid).collect::<Vec<_>>(),
+            vec![(2, 0), (2, 1), (2, 2), (3, 2)]
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use grid::{Color, Grid, Renderer};
+
+use crate::part_number::{PartNumber, PartNumbers};
+
+/// Anything in the schematic other than a digit or `.`
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Symbol {
+    pub id: usize,
+    pub row_idx: usize,
+    pub col_idx: usize,
+    pub value: char,
+}
+
+impl Symbol {
+    fn is_symbol(c: &char) -> bool {
+        !c.is_ascii_digit() && *c != '.'
+    }
+}
+
+/// An engine schematic, with every part number and symbol and which of them touch
+#[derive(Clone, Debug)]
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub part_numbers: PartNumbers,
+    symbols: Vec<Symbol>,
+    /// The ids of the symbols touching each part number, by label
+    part_symbols: Vec<Vec<usize>>,
+    /// The labels of the part numbers touching each symbol, by id
+    symbol_parts: Vec<Vec<usize>>,
+}
+
+impl From<Grid<char>> for Schematic {
+    fn from(grid: Grid<char>) -> Self {
+        let part_numbers = PartNumbers::new(&grid);
+
+        let symbols = grid
+            .rows()
+            .enumerate()
+            .flat_map(|(row_idx, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, c)| Symbol::is_symbol(c))
+                    .map(move |(col_idx, c)| (row_idx, col_idx, *c))
+            })
+            .enumerate()
+            .map(|(id, (row_idx, col_idx, value))| Symbol {
+                id,
+                row_idx,
+                col_idx,
+                value,
+            })
+            .collect::<Vec<_>>();
+
+        let mut symbol_ids = grid.map(|_| None);
+        for symbol in &symbols {
+            symbol_ids[(symbol.row_idx, symbol.col_idx)] = Some(symbol.id);
+        }
+
+        let part_symbols = part_numbers
+            .iter()
+            .map(|part_number| {
+                part_number
+                    .neighbor_ring(&grid)
+                    .filter_map(|position| symbol_ids[position])
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut symbol_parts = vec![Vec::new(); symbols.len()];
+        for (label, ids) in part_symbols.iter().enumerate() {
+            for id in ids {
+                symbol_parts[*id].push(label);
+            }
+        }
+
+        Self {
+            grid,
+            part_numbers,
+            symbols,
+            part_symbols,
+            symbol_parts,
+        }
+    }
+}
+
+impl Schematic {
+    pub fn parts(&self) -> impl Iterator<Item = PartNumber> + '_ {
+        self.part_numbers.iter()
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// The symbols touching `part_number`, including diagonally
+    pub fn symbols_touching(&self, part_number: &PartNumber) -> impl Iterator<Item = &Symbol> {
+        self.part_symbols[part_number.label]
+            .iter()
+            .map(|id| &self.symbols[*id])
+    }
+
+    /// The part numbers touching `symbol`, including diagonally
+    pub fn parts_touching(&self, symbol: &Symbol) -> impl Iterator<Item = PartNumber> + '_ {
+        self.symbol_parts[symbol.id]
+            .iter()
+            .map(|label| self.part_numbers.get(*label))
+    }
+
+    /// The part numbers touching at least one symbol
+    pub fn engine_parts(&self) -> impl Iterator<Item = PartNumber> + '_ {
+        self.parts()
+            .filter(|part_number| !self.part_symbols[part_number.label].is_empty())
+    }
+
+    /// The numbers that do not touch any symbol
+    pub fn orphans(&self) -> impl Iterator<Item = PartNumber> + '_ {
+        self.parts()
+            .filter(|part_number| self.part_symbols[part_number.label].is_empty())
+    }
+
+    /// The part numbers touching any symbol drawn as `value`
+    pub fn parts_touching_symbol(&self, value: char) -> impl Iterator<Item = PartNumber> + '_ {
+        self.parts().filter(move |part_number| {
+            self.symbols_touching(part_number)
+                .any(|symbol| symbol.value == value)
+        })
+    }
+
+    /// Every `*` touching exactly `count` part numbers, along with those numbers
+    pub fn gears(&self, count: usize) -> impl Iterator<Item = (&Symbol, Vec<PartNumber>)> {
+        self.symbols
+            .iter()
+            .filter(move |symbol| {
+                symbol.value == '*' && self.symbol_parts[symbol.id].len() == count
+            })
+            .map(|symbol| (symbol, self.parts_touching(symbol).collect()))
+    }
+
+    /// Draw the schematic with the part numbers that count towards the engine
+    /// in green, the ignored ones in red and the gears in yellow
+    pub fn renderer(&self) -> Renderer<'_, char> {
+        let mut renderer = self.grid.renderer();
+
+        for part_number in self.engine_parts() {
+            renderer.highlight_all(part_number.cells(), Color::Green);
+        }
+        for part_number in self.orphans() {
+            renderer.highlight_all(part_number.cells(), Color::Red);
+        }
+        for (gear, _) in self.gears(2) {
+            renderer.highlight(gear.row_idx, gear.col_idx, Color::Yellow);
+        }
+
+        renderer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schematic::*;
+
+    fn schematic() -> Schematic {
+        Schematic::from(
+            Grid::parse(
+                "467..114..
+            ...*......
+            ..35..633.
+            ......#...
+            617*......
+            .....+.58.
+            ..592.....
+            ......755.
+            ...$.*....
+            .664.598..",
+            )
+            .unwrap(),
+        )
+    }
+
+    fn values(parts: impl Iterator<Item = PartNumber>) -> Vec<usize> {
+        parts.map(|part_number| part_number.value).collect()
+    }
+
+    #[test]
+    fn symbols() {
+        let schematic = schematic();
+
+        assert_eq!(
+            schematic
+                .symbols()
+                .iter()
+                .map(|symbol| symbol.value)
+                .collect::<String>(),
+            "*#*+$*"
+        );
+        assert_eq!(
+            schematic.symbols()[2],
+            Symbol {
+                id: 2,
+                row_idx: 4,
+                col_idx: 3,
+                value: '*'
+            }
+        );
+    }
+
+    #[test]
+    fn adjacency() {
+        let schematic = schematic();
+        let star = &schematic.symbols()[0];
+
+        assert_eq!(values(schematic.parts_touching(star)), vec![467, 35]);
+
+        let part_number = schematic.part_numbers.at(2, 7).unwrap();
+        assert_eq!(part_number.value, 633);
+        assert_eq!(
+            schematic
+                .symbols_touching(&part_number)
+                .map(|symbol| symbol.value)
+                .collect::<String>(),
+            "#"
+        );
+    }
+
+    #[test]
+    fn queries() {
+        let schematic = schematic();
+
+        assert_eq!(values(schematic.orphans()), vec![114, 58]);
+        assert_eq!(
+            values(schematic.parts_touching_symbol('*')),
+            vec![467, 35, 617, 755, 598]
+        );
+        assert_eq!(
+            schematic
+                .gears(2)
+                .map(|(_, parts)| values(parts.into_iter()))
+                .collect::<Vec<_>>(),
+            vec![vec![467, 35], vec![755, 598]]
+        );
+        assert_eq!(schematic.gears(1).count(), 1);
+        assert_eq!(schematic.engine_parts().count(), 8);
+    }
+
+    #[test]
+    fn highlights() {
+        let schematic = schematic();
+        let rendered = schematic.renderer().plain();
+        let lines = rendered.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[0], "467..114..");
+        assert_eq!(lines[1], "GGG  RRR");
+        assert_eq!(lines[2], "...*......");
+        assert_eq!(lines[3], "   Y");
+        assert_eq!(lines.iter().filter(|line| line.contains('Y')).count(), 2);
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+
+use common::{ParseError, ParseResultExt, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, digit1, space1},
+    multi::separated_list1,
+    sequence::{separated_pair, tuple},
+    IResult,
+};
+
+fn card_numbers(input: &str) -> IResult<&str, Vec<u32>> {
+    separated_list1(space1, complete::u32)(input)
+}
+
+fn card_prefix(input: &str) -> IResult<&str, (&str, &str, &str, &str, &str)> {
+    tuple((tag("Card"), space1, digit1, tag(":"), space1))(input)
+}
+
+fn card_separator(input: &str) -> IResult<&str, (&str, &str, &str)> {
+    tuple((space1, tag("|"), space1))(input)
+}
+
+fn card_sections(input: &str) -> IResult<&str, (Vec<u32>, Vec<u32>)> {
+    separated_pair(card_numbers, card_separator, card_numbers)(input)
+}
+
+#[derive(Debug)]
+pub struct Card {
+    winning_numbers: HashSet<u32>,
+    selected_numbers: Vec<u32>,
+}
+
+impl Card {
+    fn new(source: &str) -> Result<Self, ParseError> {
+        let (input, _) = card_prefix(source).expected(source, "`Card ##:`")?;
+        let (win_nums, sel_nums) =
+            card_sections(input).complete(source, "card sections `## ## | ## ##`")?;
+
+        Ok(Self {
+            winning_numbers: HashSet::from_iter(win_nums),
+            selected_numbers: sel_nums,
+        })
+    }
+
+    fn count_matching(&self) -> usize {
+        self.selected_numbers
+            .iter()
+            .filter(|number| self.winning_numbers.contains(number))
+            .count()
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Card>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .map(|line| Card::new(line.trim()).map_err(|err| err.within(input, line.trim())))
+            .collect()
+    }
+
+    fn part1(cards: &Self::Input<'_>) -> Self::Part1 {
+        cards
+            .iter()
+            .map(Card::count_matching)
+            .filter_map(|count| match count {
+                0 => None,
+                c => Some(2u32.pow((c as u32) - 1)),
+            })
+            .sum()
+    }
+
+    fn part2(cards: &Self::Input<'_>) -> Self::Part2 {
+        cards
+            .iter()
+            .map(Card::count_matching)
+            .rev()
+            .fold(Vec::new(), |mut acc, count| {
+                acc.insert(0, 1 + &acc[0..count].iter().sum::<u32>());
+                acc
+            })
+            .iter()
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn card_parse_errors() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+            Card 2 13 32 20 16 61 | 61 30 68 82 17 32 24 19";
+        let err = Day04::parse(input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 19));
+        assert_eq!(err.expected, "`Card ##:`");
+
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+            Card 2: 13 32 20 16 61 | 61 30 x8 82 17 32 24 19";
+        let err = Day04::parse(input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 44));
+        assert_eq!(
+            err.snippet.trim(),
+            "Card 2: 13 32 20 16 61 | 61 30 x8 82 17 32 24 19"
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let schematic = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+            Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+            Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+            Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+            Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+            Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        assert_eq!(Day04::solve_part1(schematic), Ok(13));
+    }
+
+    #[test]
+    fn test_part2() {
+        let schematic = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+            Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+            Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+            Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+            Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+            Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        assert_eq!(Day04::solve_part2(schematic), Ok(30));
+    }
+}
@@ -71,3 +71,36 @@ impl_almanac_property!(Light, Temperature);
 impl_almanac_property!(Temperature, Humidity);
 impl_almanac_property!(Humidity, Location);
 impl_almanac_property!(Location);
+
+/// Every seed number followed by each of the maps from seeds to locations
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub seed_to_soil: Vec<MapInput>,
+    pub soil_to_fertilizer: Vec<MapInput>,
+    pub fertilizer_to_water: Vec<MapInput>,
+    pub water_to_light: Vec<MapInput>,
+    pub light_to_temperature: Vec<MapInput>,
+    pub temperature_to_humidity: Vec<MapInput>,
+    pub humidity_to_location: Vec<MapInput>,
+}
+
+impl Almanac {
+    /// The seed numbers read as pairs of `start count`, which parsing checks
+    /// are complete and hold at least one seed each
+    pub fn seed_ranges(&self) -> Vec<SeedRange> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| SeedRange(pair[0], pair[1]))
+            .collect()
+    }
+
+    pub fn location(&self, seed: Seed) -> Location {
+        seed.next(&self.seed_to_soil)
+            .next(&self.soil_to_fertilizer)
+            .next(&self.fertilizer_to_water)
+            .next(&self.water_to_light)
+            .next(&self.light_to_temperature)
+            .next(&self.temperature_to_humidity)
+            .next(&self.humidity_to_location)
+    }
+}
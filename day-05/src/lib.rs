@@ -1,2 +1,132 @@
 pub mod almanac;
 pub mod parsing;
+
+use common::{ParseError, Solution};
+use rayon::prelude::*;
+
+use crate::{
+    almanac::{Almanac, Location, Seed},
+    parsing::almanac,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Almanac;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        almanac(input)
+    }
+
+    fn part1(almanac: &Self::Input<'_>) -> Self::Part1 {
+        almanac
+            .seeds
+            .par_iter()
+            .map(|seed| match almanac.location(Seed(*seed)) {
+                Location(x) => x,
+            })
+            .min()
+            .expect("there should be a min")
+    }
+
+    fn part2(almanac: &Self::Input<'_>) -> Self::Part2 {
+        almanac
+            .seed_ranges()
+            .into_par_iter()
+            .filter_map(|range| {
+                range
+                    .seeds()
+                    .map(|seed| match almanac.location(seed) {
+                        Location(x) => x,
+                    })
+                    .min()
+            })
+            .min()
+            .expect("parsing should have checked for at least one seed range")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_part1() {
+        let schematic = "seeds: 79 14 55 13
+
+            seed-to-soil map:
+            50 98 2
+            52 50 48
+
+            soil-to-fertilizer map:
+            0 15 37
+            37 52 2
+            39 0 15
+
+            fertilizer-to-water map:
+            49 53 8
+            0 11 42
+            42 0 7
+            57 7 4
+
+            water-to-light map:
+            88 18 7
+            18 25 70
+
+            light-to-temperature map:
+            45 77 23
+            81 45 19
+            68 64 13
+
+            temperature-to-humidity map:
+            0 69 1
+            1 0 69
+
+            humidity-to-location map:
+            60 56 37
+            56 93 4";
+        assert_eq!(Day05::solve_part1(schematic), Ok(35));
+    }
+
+    #[test]
+    fn test_part2() {
+        let schematic = "seeds: 79 14 55 13
+
+            seed-to-soil map:
+            50 98 2
+            52 50 48
+
+            soil-to-fertilizer map:
+            0 15 37
+            37 52 2
+            39 0 15
+
+            fertilizer-to-water map:
+            49 53 8
+            0 11 42
+            42 0 7
+            57 7 4
+
+            water-to-light map:
+            88 18 7
+            18 25 70
+
+            light-to-temperature map:
+            45 77 23
+            81 45 19
+            68 64 13
+
+            temperature-to-humidity map:
+            0 69 1
+            1 0 69
+
+            humidity-to-location map:
+            60 56 37
+            56 93 4";
+        assert_eq!(Day05::solve_part2(schematic), Ok(46));
+    }
+}
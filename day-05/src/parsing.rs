@@ -1,3 +1,4 @@
+use common::{ParseError, ParseResultExt};
 use nom::{
     character::complete::{self, multispace1, space1},
     multi::separated_list1,
@@ -6,7 +7,7 @@ use nom::{
 };
 use nom_supreme::{parser_ext::ParserExt, tag::complete::tag};
 
-use crate::almanac::{MapInput, Seed, SeedRange};
+use crate::almanac::{Almanac, MapInput, Seed, SeedRange};
 
 pub fn seed_range(input: &str) -> IResult<&str, SeedRange> {
     separated_pair(complete::u64, space1, complete::u64)
@@ -44,6 +45,69 @@ pub fn mapped_inputs(header: &'static str) -> impl Fn(&str) -> IResult<&str, Vec
     }
 }
 
+fn section<'a>(
+    source: &'a str,
+    input: &'a str,
+    header: &'static str,
+) -> Result<(&'a str, Vec<MapInput>), ParseError> {
+    mapped_inputs(header)(input)
+        .expected(source, &format!("`{header}` followed by `## ## ##` rows"))
+}
+
+/// Check that the seeds pair up into `start count` ranges of at least one seed
+fn check_seed_ranges(source: &str, seeds: &[Seed]) -> Result<(), ParseError> {
+    // The seeds are all on the first line, after `seeds:`
+    let line = source.lines().next().unwrap_or_default();
+    let numbers = line.split_whitespace().skip(1).collect::<Vec<_>>();
+
+    if numbers.len() % 2 == 1 {
+        let start = numbers[numbers.len() - 1];
+        return Err(ParseError::at(
+            source,
+            &start[start.len()..],
+            "a seed count",
+        ));
+    }
+
+    match seeds
+        .iter()
+        .zip(&numbers)
+        .skip(1)
+        .step_by(2)
+        .find(|(Seed(count), _)| *count == 0)
+    {
+        Some((_, count)) => Err(ParseError::at(source, count, "a seed count of at least 1")),
+        None => Ok(()),
+    }
+}
+
+pub fn almanac(source: &str) -> Result<Almanac, ParseError> {
+    let (input, seeds) = seeds(source).expected(source, "`seeds: ## ##`")?;
+    check_seed_ranges(source, &seeds)?;
+    let (input, seed_to_soil) = section(source, input, "seed-to-soil map:")?;
+    let (input, soil_to_fertilizer) = section(source, input, "soil-to-fertilizer map:")?;
+    let (input, fertilizer_to_water) = section(source, input, "fertilizer-to-water map:")?;
+    let (input, water_to_light) = section(source, input, "water-to-light map:")?;
+    let (input, light_to_temperature) = section(source, input, "light-to-temperature map:")?;
+    let (input, temperature_to_humidity) = section(source, input, "temperature-to-humidity map:")?;
+    let (input, humidity_to_location) = section(source, input, "humidity-to-location map:")?;
+
+    if !input.trim().is_empty() {
+        return Err(ParseError::at(source, input.trim_start(), "end of almanac"));
+    }
+
+    Ok(Almanac {
+        seeds: seeds.into_iter().map(|Seed(seed)| seed).collect(),
+        seed_to_soil,
+        soil_to_fertilizer,
+        fertilizer_to_water,
+        water_to_light,
+        light_to_temperature,
+        temperature_to_humidity,
+        humidity_to_location,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::parsing::*;
@@ -133,6 +197,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn almanac_errors() {
+        let input = "seeds: 79 14 55 13
+
+            seed-to-soil map:
+            50 98 2
+            52 50 48
+
+            soil-to-fertilizer map:
+            0 15 37";
+        let err = almanac(input).err().unwrap();
+
+        assert_eq!((err.line, err.column), (8, 20));
+        assert_eq!(
+            err.expected,
+            "`fertilizer-to-water map:` followed by `## ## ##` rows"
+        );
+
+        let err = almanac("seed: 79 14").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+
+    #[test]
+    fn uneven_seed_ranges() {
+        let sections = "
+
+            seed-to-soil map:
+            50 98 2
+
+            soil-to-fertilizer map:
+            0 15 37
+
+            fertilizer-to-water map:
+            49 53 8
+
+            water-to-light map:
+            88 18 7
+
+            light-to-temperature map:
+            45 77 23
+
+            temperature-to-humidity map:
+            0 69 1
+
+            humidity-to-location map:
+            60 56 37";
+        assert!(almanac(&format!("seeds: 79 14 55 13{sections}")).is_ok());
+
+        let err = almanac(&format!("seeds: 79 14 55{sections}"))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (1, 16));
+        assert_eq!(err.expected, "a seed count");
+
+        let err = almanac(&format!("seeds: 79{sections}")).err().unwrap();
+        assert_eq!((err.line, err.column), (1, 10));
+
+        let err = almanac(&format!("seeds: 79 00 55 13{sections}"))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (1, 11));
+        assert_eq!(err.expected, "a seed count of at least 1");
+    }
+
     #[test]
     fn seed_ranges() {
         let input = "seeds: 79 14 55 13";
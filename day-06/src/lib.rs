@@ -0,0 +1,112 @@
+use common::{ParseError, ParseResultExt, Solution};
+use nom::{
+    character::complete::{self, multispace1, space1},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+
+fn get_margin(hold_time: i64, target_distance: i64) -> i64 {
+    let hold_time = hold_time as f64;
+    let target_distance = target_distance as f64;
+
+    let max_distance = (hold_time * hold_time) / 4.0;
+    let max_distance_time = hold_time / 2.0;
+
+    let low = (max_distance_time - (max_distance - target_distance).sqrt()).floor() as i64;
+    let high = (max_distance_time + (max_distance - target_distance).sqrt()).ceil() as i64;
+
+    high - low - 1
+}
+
+fn parse_values(input: &str) -> IResult<&str, (Vec<i64>, Vec<i64>)> {
+    separated_pair(
+        tag("Time:")
+            .precedes(space1)
+            .precedes(separated_list1(space1, complete::i64)),
+        multispace1,
+        tag("Distance:")
+            .precedes(space1)
+            .precedes(separated_list1(space1, complete::i64)),
+    )(input)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = (Vec<i64>, Vec<i64>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_values(input).complete(input, "`Time:` and `Distance:` rows of numbers")
+    }
+
+    fn part1((times, distances): &Self::Input<'_>) -> Self::Part1 {
+        times
+            .iter()
+            .zip(distances)
+            .map(|(time, distance)| get_margin(*time, *distance))
+            .product()
+    }
+
+    fn part2((times, distances): &Self::Input<'_>) -> Self::Part2 {
+        let time = times
+            .iter()
+            .fold(String::new(), |acc, time| acc + &time.to_string())
+            .parse()
+            .expect("time expected");
+
+        let distance = distances
+            .iter()
+            .fold(String::new(), |acc, distance| acc + &distance.to_string())
+            .parse()
+            .expect("distance expected");
+
+        get_margin(time, distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_margins() {
+        assert_eq!(get_margin(7, 9), 4);
+        assert_eq!(get_margin(15, 40), 8);
+        assert_eq!(get_margin(30, 200), 9);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "Time:      7  15   30
+            Distanse:  9  40  200";
+        let err = Day06::parse(input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 13));
+
+        let input = "Time:      7  15   30
+            Distance:  9  40  200x";
+        let err = Day06::parse(input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 34));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = "Time:      7  15   30
+            Distance:  9  40  200";
+        assert_eq!(Day06::solve_part1(input), Ok(288));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "Time:      7  15   30
+            Distance:  9  40  200";
+        assert_eq!(Day06::solve_part2(input), Ok(71503));
+    }
+}
@@ -1,7 +1,9 @@
 use std::{cmp::Ordering, collections::HashMap};
 
+use common::{ParseError, ParseResultExt, Solution};
 use nom::{
     character::complete::{self, anychar, multispace1, space1},
+    combinator::map_opt,
     multi::separated_list1,
     sequence::{separated_pair, tuple},
     IResult,
@@ -11,16 +13,18 @@ use nom::{
 struct Card(usize);
 
 impl Card {
-    fn new(c: char) -> Self {
-        Self(match c {
+    const JOKER: Card = Card(1);
+
+    fn try_new(c: char) -> Option<Self> {
+        Some(Self(match c {
             'A' => 14,
             'K' => 13,
             'Q' => 12,
             'J' => 11,
             'T' => 10,
             'X' => 1, // NOTE: JOKER!
-            x => x.to_digit(10).expect("should be digit char") as usize,
-        })
+            x => x.to_digit(10).filter(|value| *value >= 2)? as usize,
+        }))
     }
 }
 
@@ -35,34 +39,45 @@ enum HandType {
     FiveOfAKind,
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct Hand(Card, Card, Card, Card, Card, usize);
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Hand(Card, Card, Card, Card, Card, usize);
 
 impl Hand {
     fn parse(input: &str) -> IResult<&str, Self> {
+        let card = || map_opt(anychar, Card::try_new);
         let (input, (cards, bid)) = separated_pair(
-            tuple((anychar, anychar, anychar, anychar, anychar)),
+            tuple((card(), card(), card(), card(), card())),
             space1,
             complete::u32,
         )(input)?;
 
         Ok((
             input,
-            Self(
-                Card::new(cards.0),
-                Card::new(cards.1),
-                Card::new(cards.2),
-                Card::new(cards.3),
-                Card::new(cards.4),
-                bid as usize,
-            ),
+            Self(cards.0, cards.1, cards.2, cards.3, cards.4, bid as usize),
         ))
     }
 
+    /// Treat every `J` in the hand as a joker rather than a jack
+    fn with_jokers(&self) -> Self {
+        let joker = |card: Card| match card {
+            Card(11) => Card::JOKER,
+            x => x,
+        };
+
+        Self(
+            joker(self.0),
+            joker(self.1),
+            joker(self.2),
+            joker(self.3),
+            joker(self.4),
+            self.5,
+        )
+    }
+
     fn hand_type(&self) -> HandType {
         let mut frequencies: Vec<_> = [self.0, self.1, self.2, self.3, self.4]
             .iter()
-            .filter(|card| !matches!(card, Card(1))) // Remove jokers
+            .filter(|card| **card != Card::JOKER) // Remove jokers
             .fold(HashMap::new(), |mut map, val| {
                 map.entry(val).and_modify(|frq| *frq += 1).or_insert(1);
                 map
@@ -127,8 +142,7 @@ impl From<&Hand> for (Card, Card, Card, Card, Card) {
     }
 }
 
-fn process_part1(input: &str) -> usize {
-    let (_, mut hands) = separated_list1(multispace1, Hand::parse)(input).expect("should parse");
+fn total_winnings(mut hands: Vec<Hand>) -> usize {
     hands.sort_unstable();
 
     hands
@@ -138,25 +152,27 @@ fn process_part1(input: &str) -> usize {
         .sum()
 }
 
-fn process_part2(input: &str) -> usize {
-    let input = &(input.replace('J', "X")); // Distinguish jokers
-    let (_, mut hands) = separated_list1(multispace1, Hand::parse)(input).expect("should parse");
-    hands.sort_unstable();
+pub struct Day07;
 
-    hands
-        .into_iter()
-        .enumerate()
-        .map(|(idx, hand)| (idx + 1) * hand.5)
-        .sum()
-}
+impl Solution for Day07 {
+    const DAY: u8 = 7;
 
-fn main() {
-    let aoc_input = include_str!("input.txt");
-    let part1_solution = process_part1(aoc_input);
-    let part2_solution = process_part2(aoc_input);
+    type Input<'a> = Vec<Hand>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!("Part 1 Solution: {part1_solution}");
-    println!("Part 2 Solution: {part2_solution}");
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        separated_list1(multispace1, Hand::parse)(input)
+            .complete(input, "a hand of five cards followed by a bid")
+    }
+
+    fn part1(hands: &Self::Input<'_>) -> Self::Part1 {
+        total_winnings(hands.clone())
+    }
+
+    fn part2(hands: &Self::Input<'_>) -> Self::Part2 {
+        total_winnings(hands.iter().map(Hand::with_jokers).collect())
+    }
 }
 
 #[cfg(test)]
@@ -174,15 +190,15 @@ mod tests {
     #[case('4', 4)]
     #[case('X', 1)]
     fn test_card_matching(#[case] c: char, #[case] value: usize) {
-        assert_eq!(Card::new(c), Card(value));
+        assert_eq!(Card::try_new(c).unwrap(), Card(value));
     }
 
     #[test]
     fn test_card_cmp() {
-        assert!(Card::new('A') > Card::new('T'));
-        assert!(Card::new('K') > Card::new('Q'));
-        assert!(Card::new('4') < Card::new('Q'));
-        assert!(Card::new('4') == Card::new('4'));
+        assert!(Card::try_new('A').unwrap() > Card::try_new('T').unwrap());
+        assert!(Card::try_new('K').unwrap() > Card::try_new('Q').unwrap());
+        assert!(Card::try_new('4').unwrap() < Card::try_new('Q').unwrap());
+        assert!(Card::try_new('4').unwrap() == Card::try_new('4').unwrap());
     }
 
     #[rstest]
@@ -227,6 +243,17 @@ mod tests {
         assert!(gt_hand > lt_hand);
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let input = "32T3K 765
+            T55J5 684
+            KK6Z7 28";
+        let err = Day07::parse(input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (3, 13));
+        assert_eq!(err.expected, "a hand of five cards followed by a bid");
+    }
+
     #[test]
     fn test_part1() {
         let input = "32T3K 765
@@ -234,7 +261,7 @@ mod tests {
             KK677 28
             KTJJT 220
             QQQJA 483";
-        assert_eq!(process_part1(input), 6440);
+        assert_eq!(Day07::solve_part1(input), Ok(6440));
     }
 
     #[test]
@@ -244,6 +271,6 @@ mod tests {
             KK677 28
             KTJJT 220
             QQQJA 483";
-        assert_eq!(process_part2(input), 5905);
+        assert_eq!(Day07::solve_part2(input), Ok(5905));
     }
 }
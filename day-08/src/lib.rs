@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+
+use common::{
+    search::{bfs, Graph},
+    ParseError, ParseResultExt, Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take},
+    character::complete::{self, multispace1},
+    combinator::map_res,
+    multi::{many1, separated_list1},
+    sequence::{delimited, separated_pair, terminated, tuple},
+    IResult,
+};
+use num_integer::lcm;
+
+#[derive(Debug)]
+struct DirectionParseError;
+
+type DirectionPair<'a> = (&'a str, &'a str);
+
+#[derive(Debug)]
+enum Direction {
+    Right,
+    Left,
+}
+
+impl Direction {
+    fn parse(c: char) -> Result<Self, DirectionParseError> {
+        match c {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(DirectionParseError),
+        }
+    }
+}
+
+fn directions(input: &str) -> IResult<&str, Vec<Direction>> {
+    many1(map_res(
+        alt((complete::char('L'), complete::char('R'))),
+        Direction::parse,
+    ))(input)
+}
+
+fn mapping(input: &str) -> IResult<&str, Vec<(&str, DirectionPair<'_>)>> {
+    separated_list1(
+        multispace1,
+        tuple((
+            take(3usize),
+            delimited(
+                tag(" = ("),
+                separated_pair(take(3usize), tag(", "), take(3usize)),
+                tag(")"),
+            ),
+        )),
+    )(input)
+}
+
+/// The left/right instructions along with the node each step leads to
+#[derive(Debug)]
+pub struct Network<'a> {
+    directions: Vec<Direction>,
+    nodes: HashMap<&'a str, DirectionPair<'a>>,
+}
+
+fn aoc_input(source: &str) -> Result<Network<'_>, ParseError> {
+    let (input, directions) = terminated(directions, multispace1)(source)
+        .expected(source, "`L` or `R` directions followed by a blank line")?;
+    let mappings = mapping(input).complete(source, "a node like `AAA = (BBB, CCC)`")?;
+
+    Ok(Network {
+        directions,
+        nodes: mappings.into_iter().collect(),
+    })
+}
+
+/// Following the directions from a node, where each step also advances to the
+/// next direction
+///
+/// Nodes are the current node's name and the index of the next direction.
+impl<'a> Graph for Network<'a> {
+    type Node = (&'a str, usize);
+
+    fn neighbors(&self, &(node, step): &Self::Node) -> impl Iterator<Item = (Self::Node, u64)> {
+        let next = self.nodes.get(node).map(|(left, right)| {
+            let node = match self.directions[step] {
+                Direction::Left => *left,
+                Direction::Right => *right,
+            };
+            ((node, (step + 1) % self.directions.len()), 1)
+        });
+
+        next.into_iter()
+    }
+}
+
+impl<'a> Network<'a> {
+    fn path_length_to_end(&self, start_node: &'a str) -> usize {
+        // Based on part 2 (and testing), we can just check for ending in Z
+        bfs(self, (start_node, 0), |(node, _)| node.ends_with('Z'))
+            .expect("should reach a node ending in Z")
+            .steps()
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Network<'a>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        aoc_input(input)
+    }
+
+    fn part1(network: &Self::Input<'_>) -> Self::Part1 {
+        network.path_length_to_end("AAA")
+    }
+
+    fn part2(network: &Self::Input<'_>) -> Self::Part2 {
+        // Based on testing assumptions and input observation,
+        // the LCM of the path length's to the terminal nodes
+        // can be used for the result. This is largely becauase
+        // the inputs are circular.
+
+        network
+            .nodes
+            .keys()
+            .filter(|key| key.ends_with('A'))
+            .map(|key| network.path_length_to_end(key))
+            .fold(1, lcm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "RLX
+
+AAA = (BBB, CCC)";
+        let err = Day08::parse(input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (1, 3));
+
+        let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD EEE)
+CCC = (ZZZ, GGG)";
+        let err = Day08::parse(input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.expected, "a node like `AAA = (BBB, CCC)`");
+    }
+
+    #[test]
+    fn test_part1_1() {
+        let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(Day08::solve_part1(input), Ok(2));
+    }
+
+    #[test]
+    fn test_graph_path() {
+        let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let network = Day08::parse(input).unwrap();
+        let path = bfs(&network, ("AAA", 0), |(node, _)| *node == "ZZZ").unwrap();
+
+        assert_eq!(
+            path.nodes,
+            vec![
+                ("AAA", 0),
+                ("BBB", 1),
+                ("AAA", 2),
+                ("BBB", 0),
+                ("AAA", 1),
+                ("BBB", 2),
+                ("ZZZ", 0)
+            ]
+        );
+        assert_eq!(
+            network.neighbors(&("ZZZ", 2)).collect::<Vec<_>>(),
+            vec![(("ZZZ", 0), 1)]
+        );
+    }
+
+    #[test]
+    fn test_part1_2() {
+        let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(Day08::solve_part1(input), Ok(6));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        assert_eq!(Day08::solve_part2(input), Ok(6));
+    }
+}
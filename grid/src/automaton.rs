@@ -0,0 +1,273 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+    mem,
+};
+
+use rayon::prelude::*;
+
+use crate::{
+    cell::Cell,
+    grid::Grid,
+    neighborhood::{Edges, Kind, Neighborhood},
+};
+
+/// Where a sequence of generations starts repeating itself
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Cycle {
+    /// The first generation that comes back later on
+    pub start: usize,
+    /// The number of generations until it comes back
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The generation at or before the first repeat that is the same as `generation`
+    pub fn equivalent(&self, generation: usize) -> usize {
+        if generation < self.start {
+            generation
+        } else {
+            self.start + (generation - self.start) % self.period
+        }
+    }
+}
+
+/// Steps a grid through generations, computing every cell of the next one from
+/// the cell and its neighbors in the current one
+///
+/// Generations are double-buffered, so stepping does not allocate.
+pub struct Automaton<'k, T, F> {
+    current: Grid<T>,
+    next: Grid<T>,
+    rule: F,
+    kind: Kind<'k>,
+    edges: Edges,
+    parallel: bool,
+    generation: usize,
+}
+
+impl<'k, T, F> Automaton<'k, T, F>
+where
+    T: Clone + Send + Sync,
+    F: Fn(&Cell<'_, T>, Neighborhood<'_, T>) -> T + Sync,
+{
+    /// Start from `grid` as generation 0, giving `rule` the neighbors of the
+    /// given kind with the given edges
+    pub fn new(grid: Grid<T>, kind: Kind<'k>, edges: Edges, rule: F) -> Self {
+        Self {
+            next: grid.clone(),
+            current: grid,
+            rule,
+            kind,
+            edges,
+            parallel: false,
+            generation: 0,
+        }
+    }
+
+    /// Compute the cells of each generation in parallel
+    pub fn parallel(mut self) -> Self {
+        self.parallel = true;
+        self
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.current
+    }
+
+    /// The number of steps taken so far
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn step(&mut self) {
+        let Self {
+            current,
+            next,
+            rule,
+            kind,
+            edges,
+            parallel,
+            ..
+        } = self;
+
+        let current = &*current;
+        let width = current.width();
+        let update = |(offset, value): (usize, &mut T)| {
+            let cell = current
+                .cell(offset / width, offset % width)
+                .expect("both generations should have the same size");
+            *value = rule(&cell, Neighborhood::new(&cell, *kind, *edges));
+        };
+
+        if *parallel {
+            next.values_mut()
+                .par_iter_mut()
+                .enumerate()
+                .for_each(update);
+        } else {
+            next.values_mut().iter_mut().enumerate().for_each(update);
+        }
+
+        mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+    }
+
+    pub fn run(&mut self, steps: usize) -> &Grid<T> {
+        for _ in 0..steps {
+            self.step();
+        }
+        &self.current
+    }
+
+    /// Step until a generation is left unchanged by the rule, taking at most `max_steps`
+    ///
+    /// Returns that generation, or `None` if none was found in time.
+    pub fn fixed_point(&mut self, max_steps: usize) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        for _ in 0..max_steps {
+            self.step();
+            if self.current == self.next {
+                return Some(self.generation - 1);
+            }
+        }
+
+        None
+    }
+
+    /// Step until a generation repeats an earlier one, taking at most `max_steps`
+    ///
+    /// The automaton is left at the first repeat, `start + period`. A fixed
+    /// point is a cycle with a period of 1.
+    pub fn find_cycle(&mut self, max_steps: usize) -> Option<Cycle>
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashMap::from([(self.current.clone(), self.generation)]);
+
+        for _ in 0..max_steps {
+            self.step();
+            match seen.entry(self.current.clone()) {
+                Entry::Occupied(entry) => {
+                    let start = *entry.get();
+                    return Some(Cycle {
+                        start,
+                        period: self.generation - start,
+                    });
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(self.generation);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::automaton::*;
+
+    /// Conway's Game of Life, with `#` for live cells
+    fn life(cell: &Cell<char>, neighbors: Neighborhood<char>) -> char {
+        let alive = neighbors.filter(|neighbor| *neighbor.value == '#').count();
+        match (*cell.value, alive) {
+            ('#', 2 | 3) | ('.', 3) => '#',
+            _ => '.',
+        }
+    }
+
+    #[test]
+    fn stepping() {
+        let grid = Grid::parse(
+            ".....
+            ..#..
+            ..#..
+            ..#..
+            .....",
+        )
+        .unwrap();
+        let mut automaton = Automaton::new(grid.clone(), Kind::Moore, Edges::Clip, life);
+
+        automaton.step();
+        assert_eq!(automaton.generation(), 1);
+        assert_eq!(
+            automaton.grid().to_string(),
+            ".....\n.....\n.###.\n.....\n.....\n"
+        );
+        assert_eq!(automaton.run(3), &grid);
+
+        let mut parallel = Automaton::new(grid, Kind::Moore, Edges::Clip, life).parallel();
+        assert_eq!(parallel.run(4), automaton.grid());
+    }
+
+    #[test]
+    fn fixed_points() {
+        let block = Grid::parse(
+            "....
+            .##.
+            .#..
+            ....",
+        )
+        .unwrap();
+        let mut automaton = Automaton::new(block, Kind::Moore, Edges::Clip, life);
+
+        assert_eq!(automaton.fixed_point(10), Some(1));
+        assert_eq!(automaton.grid().to_string(), "....\n.##.\n.##.\n....\n");
+
+        let blinker = Grid::parse(
+            "...
+            ###
+            ...",
+        )
+        .unwrap();
+        let mut automaton = Automaton::new(blinker, Kind::Moore, Edges::Clip, life);
+        assert_eq!(automaton.fixed_point(10), None);
+    }
+
+    #[test]
+    fn cycles() {
+        let glider = Grid::parse(
+            ".#...
+            ..#..
+            ###..
+            .....
+            .....",
+        )
+        .unwrap();
+        let mut automaton = Automaton::new(glider.clone(), Kind::Moore, Edges::Wrap, life);
+
+        let cycle = automaton.find_cycle(100).unwrap();
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 0,
+                period: 20
+            }
+        );
+        assert_eq!(automaton.grid(), &glider);
+        assert_eq!(cycle.equivalent(1_000_000_003), 3);
+
+        let block = Grid::parse(
+            ".##
+            .#.",
+        )
+        .unwrap();
+        let mut automaton = Automaton::new(block, Kind::Moore, Edges::Clip, life);
+        assert_eq!(
+            automaton.find_cycle(10),
+            Some(Cycle {
+                start: 1,
+                period: 1
+            })
+        );
+        assert_eq!(automaton.generation(), 2);
+    }
+}
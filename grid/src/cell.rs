@@ -0,0 +1,207 @@
+use crate::grid::Grid;
+use crate::neighborhood::{Edges, Kind, Neighborhood};
+
+/// A value within a grid, along with its position
+#[derive(PartialEq, Debug)]
+pub struct Cell<'a, T> {
+    pub grid: &'a Grid<T>,
+    pub row_idx: usize,
+    pub col_idx: usize,
+    pub value: &'a T,
+}
+
+impl<'a, T> Clone for Cell<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for Cell<'a, T> {}
+
+impl<'a, T> Cell<'a, T> {
+    /// The surrounding cells of the given kind that are within the grid
+    pub fn neighbors(&self, kind: Kind<'a>) -> Neighborhood<'a, T> {
+        Neighborhood::new(self, kind, Edges::Clip)
+    }
+
+    /// The surrounding cells of the given kind, wrapping around the edges of the grid
+    pub fn wrapping_neighbors(&self, kind: Kind<'a>) -> Neighborhood<'a, T> {
+        Neighborhood::new(self, kind, Edges::Wrap)
+    }
+}
+
+/// The order in which cells are visited
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Order {
+    /// Left to right along each row, from the top row to the bottom one
+    RowMajor,
+    /// Top to bottom along each column, from the left column to the right one
+    ColumnMajor,
+}
+
+/// An iterator over every cell within a rectangle of a grid
+pub struct Cells<'a, T> {
+    grid: &'a Grid<T>,
+    top: usize,
+    left: usize,
+    height: usize,
+    width: usize,
+    order: Order,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Cells<'a, T> {
+    pub fn new(grid: &'a Grid<T>, order: Order) -> Self {
+        Self::within(grid, (0, 0), (grid.height(), grid.width()), order)
+    }
+
+    /// Visit the `height` by `width` cells from `(top, left)`, clipped to the grid
+    pub(crate) fn within(
+        grid: &'a Grid<T>,
+        (top, left): (usize, usize),
+        (height, width): (usize, usize),
+        order: Order,
+    ) -> Self {
+        let height = height.min(grid.height().saturating_sub(top));
+        let width = width.min(grid.width().saturating_sub(left));
+
+        Self {
+            grid,
+            top,
+            left,
+            height,
+            width,
+            order,
+            front: 0,
+            back: height * width,
+        }
+    }
+
+    fn cell(&self, idx: usize) -> Cell<'a, T> {
+        let (row_offset, col_offset) = match self.order {
+            Order::RowMajor => (idx / self.width, idx % self.width),
+            Order::ColumnMajor => (idx % self.height, idx / self.height),
+        };
+
+        self.grid
+            .cell(self.top + row_offset, self.left + col_offset)
+            .expect("cells should be clipped to the grid")
+    }
+}
+
+impl<'a, T> Iterator for Cells<'a, T> {
+    type Item = Cell<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let cell = self.cell(self.front);
+        self.front += 1;
+
+        Some(cell)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Cells<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(self.cell(self.back))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Cells<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cell::*, region::BoundingBox};
+
+    fn visited(cells: Cells<char>) -> String {
+        cells.map(|cell| *cell.value).collect()
+    }
+
+    #[test]
+    fn every_cell_once() {
+        let grid = Grid::parse(
+            "abc
+            def",
+        )
+        .unwrap();
+
+        assert_eq!(visited(grid.cells_row_major()), "abcdef");
+        assert_eq!(visited(grid.cells_col_major()), "adbecf");
+        assert_eq!(visited(grid.cells()), visited(grid.cells_row_major()));
+        assert_eq!(
+            visited(grid.cells_row_major()).len(),
+            grid.width() * grid.height()
+        );
+        assert_eq!(
+            grid.cells_col_major()
+                .rev()
+                .map(|cell| *cell.value)
+                .collect::<String>(),
+            "fcebda"
+        );
+        assert_eq!(grid.cells_col_major().len(), 6);
+
+        let empty = Grid::<char>::from_rows(Vec::<Vec<char>>::new()).unwrap();
+        assert_eq!(empty.cells().count(), 0);
+    }
+
+    #[test]
+    fn rows_columns_and_rectangles() {
+        let grid = Grid::parse(
+            "abcd
+            efgh
+            ijkl",
+        )
+        .unwrap();
+
+        assert_eq!(visited(grid.row(1)), "efgh");
+        assert_eq!(visited(grid.col(2)), "cgk");
+        assert_eq!(visited(grid.row(3)), "");
+        assert_eq!(visited(grid.col(4)), "");
+
+        let rows = (0..grid.height()).map(|row_idx| visited(grid.row(row_idx)));
+        assert_eq!(rows.collect::<String>(), visited(grid.cells_row_major()));
+        let cols = (0..grid.width()).map(|col_idx| visited(grid.col(col_idx)));
+        assert_eq!(cols.collect::<String>(), visited(grid.cells_col_major()));
+
+        let bounds = BoundingBox {
+            top: 1,
+            left: 1,
+            bottom: 2,
+            right: 5,
+        };
+        assert_eq!(visited(grid.cells_within(&bounds)), "fghjkl");
+    }
+
+    #[test]
+    fn cells_know_their_position() {
+        let grid = Grid::parse(
+            "ab
+            cd
+            ef",
+        )
+        .unwrap();
+        let cell = grid.cell(2, 1).unwrap();
+
+        assert_eq!((cell.row_idx, cell.col_idx, *cell.value), (2, 1, 'f'));
+        assert!(grid.cell(3, 0).is_none());
+        assert!(grid
+            .cells()
+            .all(|cell| grid.get(cell.row_idx, cell.col_idx) == Some(cell.value)));
+    }
+}
@@ -0,0 +1,368 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    cell::{Cell, Cells, Order},
+    region::BoundingBox,
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum GridError {
+    /// A row, counted from 0, did not have as many values as the rows before it
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The values could not be split into rows of the given width
+    Size { width: usize, len: usize },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {found} values instead of {expected}",
+                row + 1
+            ),
+            GridError::Size { width, len } => {
+                write!(f, "{len} values cannot be split into rows of {width}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// A rectangular grid of values, stored row by row
+///
+/// Grids without any rows or columns are empty, with a width and height of 0.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    values: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+/// The non-blank lines of `input` without their surrounding whitespace
+fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.trim().lines().map(str::trim)
+}
+
+impl Grid<char> {
+    /// Build a grid of characters from lines of text, ignoring surrounding
+    /// whitespace and blank lines at the start and end
+    pub fn parse(input: &str) -> Result<Self, GridError> {
+        Self::from_rows(lines(input).map(str::chars))
+    }
+
+    /// Build a grid of characters like [`Grid::parse`], padding short lines with `fill`
+    pub fn parse_padded(input: &str, fill: char) -> Self {
+        Self::from_rows_padded(lines(input).map(str::chars), fill)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from `values` laid out row by row, `width` values at a time
+    ///
+    /// Without any values the grid is empty, whatever the `width`.
+    pub fn from_vec(width: usize, values: Vec<T>) -> Result<Self, GridError> {
+        let (width, height) = match (width, values.len()) {
+            (0, _) | (_, 0) => (0, 0),
+            (width, len) => (width, len / width),
+        };
+
+        if width * height != values.len() {
+            return Err(GridError::Size {
+                width,
+                len: values.len(),
+            });
+        }
+
+        Ok(Self {
+            values,
+            width,
+            height,
+        })
+    }
+
+    /// Build a grid from its rows, which must all have the same length
+    pub fn from_rows<R: IntoIterator<Item = T>>(
+        rows: impl IntoIterator<Item = R>,
+    ) -> Result<Self, GridError> {
+        let mut values = Vec::new();
+        let mut width = None;
+
+        for (row_idx, row) in rows.into_iter().enumerate() {
+            let start = values.len();
+            values.extend(row);
+
+            let found = values.len() - start;
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(GridError::Ragged {
+                    row: row_idx,
+                    expected,
+                    found,
+                });
+            }
+        }
+
+        Self::from_vec(width.unwrap_or(0), values)
+    }
+
+    /// Build a grid from its rows, padding each one with `fill` up to the longest
+    pub fn from_rows_padded<R: IntoIterator<Item = T>>(
+        rows: impl IntoIterator<Item = R>,
+        fill: T,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+        let values = rows
+            .into_iter()
+            .flat_map(|mut row| {
+                row.resize(width, fill.clone());
+                row
+            })
+            .collect();
+
+        Self::from_vec(width, values).expect("padded rows should all have the same length")
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    fn offset(&self, row_idx: usize, col_idx: usize) -> Option<usize> {
+        (row_idx < self.height && col_idx < self.width).then_some(row_idx * self.width + col_idx)
+    }
+
+    /// Every value, row by row
+    pub(crate) fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    pub fn get(&self, row_idx: usize, col_idx: usize) -> Option<&T> {
+        self.offset(row_idx, col_idx)
+            .map(|offset| &self.values[offset])
+    }
+
+    pub fn get_mut(&mut self, row_idx: usize, col_idx: usize) -> Option<&mut T> {
+        self.offset(row_idx, col_idx)
+            .map(|offset| &mut self.values[offset])
+    }
+
+    pub fn cell(&self, row_idx: usize, col_idx: usize) -> Option<Cell<'_, T>> {
+        let value = self.get(row_idx, col_idx)?;
+
+        Some(Cell {
+            grid: self,
+            row_idx,
+            col_idx,
+            value,
+        })
+    }
+
+    /// Every cell, in row-major order
+    pub fn cells(&self) -> Cells<'_, T> {
+        self.cells_row_major()
+    }
+
+    pub fn cells_row_major(&self) -> Cells<'_, T> {
+        Cells::new(self, Order::RowMajor)
+    }
+
+    pub fn cells_col_major(&self) -> Cells<'_, T> {
+        Cells::new(self, Order::ColumnMajor)
+    }
+
+    /// The cells of a row from left to right, or none if it is outside of the grid
+    pub fn row(&self, row_idx: usize) -> Cells<'_, T> {
+        Cells::within(self, (row_idx, 0), (1, self.width), Order::RowMajor)
+    }
+
+    /// The cells of a column from top to bottom, or none if it is outside of the grid
+    pub fn col(&self, col_idx: usize) -> Cells<'_, T> {
+        Cells::within(self, (0, col_idx), (self.height, 1), Order::ColumnMajor)
+    }
+
+    /// The cells within `bounds` in row-major order, ignoring any part outside of the grid
+    pub fn cells_within(&self, bounds: &BoundingBox) -> Cells<'_, T> {
+        Cells::within(
+            self,
+            (bounds.top, bounds.left),
+            (bounds.height(), bounds.width()),
+            Order::RowMajor,
+        )
+    }
+
+    /// Build a grid of the same shape from every value
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            values: self.values.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Iterate over each row from top to bottom
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+        (0..self.height).map(|row_idx| &self.values[row_idx * self.width..][..self.width])
+    }
+
+    /// Iterate over each column from left to right, each going from top to bottom
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = impl Iterator<Item = &T>> + '_ {
+        (0..self.width).map(|col_idx| self.values[col_idx..].iter().step_by(self.width))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row_idx, col_idx): (usize, usize)) -> &Self::Output {
+        self.get(row_idx, col_idx).unwrap_or_else(|| {
+            panic!(
+                "({row_idx}, {col_idx}) is outside of the {}x{} grid",
+                self.height, self.width
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row_idx, col_idx): (usize, usize)) -> &mut Self::Output {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(row_idx, col_idx).unwrap_or_else(|| {
+            panic!("({row_idx}, {col_idx}) is outside of the {height}x{width} grid")
+        })
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    /// Draw every row on its own line
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{value}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::*;
+
+    #[test]
+    fn parsing() {
+        let grid = Grid::parse(
+            "123
+            456",
+        )
+        .unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(1, 0), Some(&'4'));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid[(0, 2)], '3');
+    }
+
+    #[test]
+    fn ragged_rows() {
+        assert_eq!(
+            Grid::from_rows([vec![1, 2], vec![3, 4], vec![5]]),
+            Err(GridError::Ragged {
+                row: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            Grid::from_vec(2, vec![1, 2, 3]),
+            Err(GridError::Size { width: 2, len: 3 })
+        );
+        assert!(Grid::parse("ab\nabc").is_err());
+
+        let padded = Grid::parse_padded("ab\nabc\n\nd", '.');
+        assert_eq!(padded, Grid::parse("ab.\nabc\n...\nd..").unwrap());
+    }
+
+    #[test]
+    fn empty_grids() {
+        for grid in [
+            Grid::parse("").unwrap(),
+            Grid::parse("  \n\n ").unwrap(),
+            Grid::from_rows([[], []]).unwrap(),
+            Grid::from_vec(3, Vec::new()).unwrap(),
+            Grid::parse_padded("", '.'),
+        ] {
+            assert!(grid.is_empty());
+            assert_eq!((grid.width(), grid.height()), (0, 0));
+            assert_eq!(grid.get(0, 0), None);
+            assert_eq!(grid.cells().count(), 0);
+            assert_eq!(grid.rows().count(), 0);
+            assert_eq!(grid.columns().count(), 0);
+            assert_eq!(grid.row(0).count(), 0);
+            assert_eq!(grid.col(0).count(), 0);
+            assert_eq!(grid.to_string(), "");
+            assert!(grid.regions(crate::Kind::Moore, |_| true).is_empty());
+        }
+
+        assert!(Grid::from_vec(0, vec!['a']).is_err());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::from_vec(3, (1..=6).collect()).unwrap();
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3], &[4, 5, 6]]
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn mapping() {
+        let mut grid = Grid::parse("1.\n.2").unwrap().map(|c| c.to_digit(10));
+        grid[(1, 0)] = Some(7);
+
+        assert_eq!(
+            grid,
+            Grid::from_rows([[Some(1), None], [Some(7), Some(2)]]).unwrap()
+        );
+    }
+}
@@ -0,0 +1,19 @@
+pub mod automaton;
+pub mod cell;
+pub mod grid;
+pub mod neighborhood;
+pub mod region;
+pub mod render;
+pub mod search;
+pub mod sparse;
+pub mod transform;
+
+pub use crate::automaton::{Automaton, Cycle};
+pub use crate::cell::{Cell, Cells, Order};
+pub use crate::grid::{Grid, GridError};
+pub use crate::neighborhood::{Edges, Kind, Neighborhood};
+pub use crate::region::{BoundingBox, Region, Regions};
+pub use crate::render::{Color, Renderer};
+pub use crate::search::GridGraph;
+pub use crate::sparse::{SparseCell, SparseGrid, SparseNeighborhood};
+pub use crate::transform::View;
@@ -0,0 +1,193 @@
+use crate::cell::Cell;
+use crate::grid::Grid;
+
+/// Which of the surrounding cells count as neighbors
+///
+/// Each kind lists `(row, column)` offsets in order of exploration, with C
+/// being the current cell:
+///
+/// ```text
+///  VonNeumann   Moore   Diagonal
+///    . 0 .      0 1 2    0 . 1
+///    1 C 2      3 C 4    . C .
+///    . 3 .      5 6 7    2 . 3
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Kind<'s> {
+    VonNeumann,
+    Moore,
+    Diagonal,
+    /// Arbitrary offsets, explored in the given order
+    Stencil(&'s [(isize, isize)]),
+}
+
+impl<'s> Kind<'s> {
+    pub fn offsets(&self) -> &'s [(isize, isize)] {
+        match self {
+            Kind::VonNeumann => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Kind::Moore => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+            Kind::Diagonal => &[(-1, -1), (-1, 1), (1, -1), (1, 1)],
+            Kind::Stencil(offsets) => offsets,
+        }
+    }
+}
+
+/// What happens to neighbors that fall outside of the grid
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Edges {
+    /// Skip them
+    Clip,
+    /// Wrap around to the opposite side, as if the grid were a torus
+    ///
+    /// Small grids may yield the same cell more than once, or the current cell itself.
+    Wrap,
+}
+
+/// An iterator that goes through the surrounding cells of a cell
+#[derive(Clone, PartialEq, Debug)]
+pub struct Neighborhood<'a, T> {
+    grid: &'a Grid<T>,
+    row_idx: usize,
+    col_idx: usize,
+    offsets: &'a [(isize, isize)],
+    edges: Edges,
+    count: usize,
+}
+
+fn wrap(idx: usize, offset: isize, len: usize) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    let len = len as isize;
+    Some((idx as isize % len + offset % len).rem_euclid(len) as usize)
+}
+
+impl<'a, T> Neighborhood<'a, T> {
+    pub fn new(cell: &Cell<'a, T>, kind: Kind<'a>, edges: Edges) -> Self {
+        Self {
+            grid: cell.grid,
+            row_idx: cell.row_idx,
+            col_idx: cell.col_idx,
+            offsets: kind.offsets(),
+            edges,
+            count: 0,
+        }
+    }
+
+    fn current_neighbor(&self) -> Option<Cell<'a, T>> {
+        let (row_offset, col_offset) = *self.offsets.get(self.count)?;
+
+        let (row_idx, col_idx) = match self.edges {
+            Edges::Clip => (
+                self.row_idx.checked_add_signed(row_offset)?,
+                self.col_idx.checked_add_signed(col_offset)?,
+            ),
+            Edges::Wrap => (
+                wrap(self.row_idx, row_offset, self.grid.height())?,
+                wrap(self.col_idx, col_offset, self.grid.width())?,
+            ),
+        };
+
+        self.grid.cell(row_idx, col_idx)
+    }
+}
+
+impl<'a, T> Iterator for Neighborhood<'a, T> {
+    type Item = Cell<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut next_value: Option<Self::Item> = None;
+
+        while next_value.is_none() && self.count < self.offsets.len() {
+            next_value = self.current_neighbor();
+            self.count += 1;
+        }
+
+        next_value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::neighborhood::*;
+
+    fn neighbors(grid: &Grid<char>, row_idx: usize, col_idx: usize, kind: Kind) -> String {
+        grid.cell(row_idx, col_idx)
+            .unwrap()
+            .neighbors(kind)
+            .map(|cell| *cell.value)
+            .collect()
+    }
+
+    fn wrapping_neighbors(grid: &Grid<char>, row_idx: usize, col_idx: usize, kind: Kind) -> String {
+        grid.cell(row_idx, col_idx)
+            .unwrap()
+            .wrapping_neighbors(kind)
+            .map(|cell| *cell.value)
+            .collect()
+    }
+
+    #[test]
+    fn exploration_order() {
+        let grid = Grid::parse(
+            "abc
+            dCe
+            fgh",
+        )
+        .unwrap();
+
+        assert_eq!(neighbors(&grid, 1, 1, Kind::Moore), "abcdefgh");
+        assert_eq!(neighbors(&grid, 1, 1, Kind::VonNeumann), "bdeg");
+        assert_eq!(neighbors(&grid, 1, 1, Kind::Diagonal), "acfh");
+        assert_eq!(
+            neighbors(&grid, 1, 1, Kind::Stencil(&[(1, 1), (0, 0), (-1, 0)])),
+            "hCb"
+        );
+    }
+
+    #[test]
+    fn edges_are_skipped() {
+        let grid = Grid::parse(
+            "abc
+            def",
+        )
+        .unwrap();
+
+        assert_eq!(neighbors(&grid, 0, 0, Kind::Moore), "bde");
+        assert_eq!(neighbors(&grid, 1, 2, Kind::Moore), "bce");
+        assert_eq!(neighbors(&grid, 0, 0, Kind::Diagonal), "e");
+        assert_eq!(neighbors(&grid, 0, 0, Kind::Stencil(&[(0, 5)])), "");
+        assert_eq!(neighbors(&Grid::parse("x").unwrap(), 0, 0, Kind::Moore), "");
+    }
+
+    #[test]
+    fn edges_wrap_around() {
+        let grid = Grid::parse(
+            "abcd
+            efgh
+            ijkl",
+        )
+        .unwrap();
+
+        assert_eq!(wrapping_neighbors(&grid, 0, 0, Kind::VonNeumann), "idbe");
+        assert_eq!(wrapping_neighbors(&grid, 2, 3, Kind::Diagonal), "geca");
+        assert_eq!(
+            wrapping_neighbors(&grid, 1, 1, Kind::Stencil(&[(-4, -9), (3, 4)])),
+            "af"
+        );
+        assert_eq!(
+            wrapping_neighbors(&Grid::parse("x").unwrap(), 0, 0, Kind::VonNeumann),
+            "xxxx"
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use std::{collections::VecDeque, ops::Index, slice};
+
+use crate::{grid::Grid, neighborhood::Kind};
+
+/// The smallest rectangle containing a set of cells, with inclusive bounds
+///
+/// Positions are unsigned for a [`Grid`] and signed for a [`SparseGrid`](crate::SparseGrid).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BoundingBox<I = usize> {
+    pub top: I,
+    pub left: I,
+    pub bottom: I,
+    pub right: I,
+}
+
+impl<I: Copy + Ord> BoundingBox<I> {
+    pub(crate) fn new((row_idx, col_idx): (I, I)) -> Self {
+        Self {
+            top: row_idx,
+            left: col_idx,
+            bottom: row_idx,
+            right: col_idx,
+        }
+    }
+
+    pub(crate) fn include(&mut self, (row_idx, col_idx): (I, I)) {
+        self.top = self.top.min(row_idx);
+        self.left = self.left.min(col_idx);
+        self.bottom = self.bottom.max(row_idx);
+        self.right = self.right.max(col_idx);
+    }
+
+    pub fn contains(&self, row_idx: I, col_idx: I) -> bool {
+        (self.top..=self.bottom).contains(&row_idx) && (self.left..=self.right).contains(&col_idx)
+    }
+}
+
+impl BoundingBox<usize> {
+    /// The number of columns, which is 0 if `right` is before `left`
+    pub fn width(&self) -> usize {
+        self.right
+            .checked_sub(self.left)
+            .map_or(0, |width| width.saturating_add(1))
+    }
+
+    /// The number of rows, which is 0 if `bottom` is above `top`
+    pub fn height(&self) -> usize {
+        self.bottom
+            .checked_sub(self.top)
+            .map_or(0, |height| height.saturating_add(1))
+    }
+}
+
+/// The number of positions from `start` to `end` inclusive, which is 0 if
+/// `end` is before `start` and saturates at `usize::MAX`
+fn span(start: i64, end: i64) -> usize {
+    (start <= end)
+        .then(|| end.abs_diff(start))
+        .map_or(0, |span| {
+            usize::try_from(span)
+                .unwrap_or(usize::MAX)
+                .saturating_add(1)
+        })
+}
+
+impl BoundingBox<i64> {
+    /// The number of columns, which is 0 if `right` is before `left`
+    pub fn width(&self) -> usize {
+        span(self.left, self.right)
+    }
+
+    /// The number of rows, which is 0 if `bottom` is above `top`
+    pub fn height(&self) -> usize {
+        span(self.top, self.bottom)
+    }
+}
+
+/// Connected cells that all matched the same predicate
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Region {
+    pub label: usize,
+    /// The `(row, column)` positions of the cells, in row-major order
+    pub cells: Vec<(usize, usize)>,
+    pub bounds: BoundingBox,
+}
+
+impl Region {
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The values of the cells within `grid`, in row-major order
+    pub fn values<'a, T>(&'a self, grid: &'a Grid<T>) -> impl Iterator<Item = &'a T> + 'a {
+        self.cells.iter().map(|&position| &grid[position])
+    }
+}
+
+/// Every region of a grid, along with the label of each cell
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Regions {
+    labels: Grid<Option<usize>>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    /// The label of the region containing the cell, if it matched
+    pub fn label(&self, row_idx: usize, col_idx: usize) -> Option<usize> {
+        *self.labels.get(row_idx, col_idx)?
+    }
+
+    /// The region containing the cell, if it matched
+    pub fn at(&self, row_idx: usize, col_idx: usize) -> Option<&Region> {
+        self.label(row_idx, col_idx)
+            .map(|label| &self.regions[label])
+    }
+
+    pub fn labels(&self) -> &Grid<Option<usize>> {
+        &self.labels
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, Region> {
+        self.regions.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+}
+
+impl Index<usize> for Regions {
+    type Output = Region;
+
+    fn index(&self, label: usize) -> &Self::Output {
+        &self.regions[label]
+    }
+}
+
+impl<'a> IntoIterator for &'a Regions {
+    type Item = &'a Region;
+    type IntoIter = slice::Iter<'a, Region>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for Regions {
+    type Item = Region;
+    type IntoIter = std::vec::IntoIter<Region>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.regions.into_iter()
+    }
+}
+
+/// Label every matching cell connected to `start` that has not been labeled yet
+fn fill(
+    mask: &Grid<bool>,
+    labels: &mut Grid<Option<usize>>,
+    start: (usize, usize),
+    kind: Kind,
+    label: usize,
+) -> Region {
+    let mut cells = vec![start];
+    let mut bounds = BoundingBox::new(start);
+    let mut queue = VecDeque::from([start]);
+    labels[start] = Some(label);
+
+    while let Some((row_idx, col_idx)) = queue.pop_front() {
+        let Some(cell) = mask.cell(row_idx, col_idx) else {
+            continue;
+        };
+
+        for neighbor in cell.neighbors(kind) {
+            let position = (neighbor.row_idx, neighbor.col_idx);
+            if *neighbor.value && labels[position].is_none() {
+                labels[position] = Some(label);
+                bounds.include(position);
+                cells.push(position);
+                queue.push_back(position);
+            }
+        }
+    }
+
+    cells.sort_unstable();
+
+    Region {
+        label,
+        cells,
+        bounds,
+    }
+}
+
+impl<T> Grid<T> {
+    /// Find the cells matching `matches` that are connected to the given cell
+    /// through neighbors of the given kind
+    ///
+    /// Returns `None` if the cell itself does not match or is outside of the grid.
+    pub fn flood_fill(
+        &self,
+        row_idx: usize,
+        col_idx: usize,
+        kind: Kind,
+        matches: impl FnMut(&T) -> bool,
+    ) -> Option<Region> {
+        let mask = self.map(matches);
+        if !*mask.get(row_idx, col_idx)? {
+            return None;
+        }
+
+        let mut labels = self.map(|_| None);
+        Some(fill(&mask, &mut labels, (row_idx, col_idx), kind, 0))
+    }
+
+    /// Group the cells matching `matches` into regions connected through
+    /// neighbors of the given kind
+    ///
+    /// Regions are labeled from 0 in row-major order of their first cell.
+    pub fn regions(&self, kind: Kind, matches: impl FnMut(&T) -> bool) -> Regions {
+        let mask = self.map(matches);
+        let mut labels = self.map(|_| None);
+        let mut regions = Vec::new();
+
+        for row_idx in 0..self.height() {
+            for col_idx in 0..self.width() {
+                let position = (row_idx, col_idx);
+                if mask[position] && labels[position].is_none() {
+                    let region = fill(&mask, &mut labels, position, kind, regions.len());
+                    regions.push(region);
+                }
+            }
+        }
+
+        Regions { labels, regions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::region::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(
+            "aab.
+            .b.a
+            bb.a
+            ...a",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn flood_fill() {
+        let region = grid()
+            .flood_fill(3, 3, Kind::VonNeumann, |c| *c == 'a')
+            .unwrap();
+
+        assert_eq!(region.cells, vec![(1, 3), (2, 3), (3, 3)]);
+        assert_eq!(
+            region.bounds,
+            BoundingBox {
+                top: 1,
+                left: 3,
+                bottom: 3,
+                right: 3
+            }
+        );
+        assert_eq!((region.bounds.width(), region.bounds.height()), (1, 3));
+
+        assert_eq!(
+            grid().flood_fill(0, 3, Kind::VonNeumann, |c| *c == 'a'),
+            None
+        );
+        assert_eq!(grid().flood_fill(9, 9, Kind::VonNeumann, |_| true), None);
+    }
+
+    #[test]
+    fn labeling_regions() {
+        let grid = grid();
+        let regions = grid.regions(Kind::VonNeumann, |c| *c == 'b');
+
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[0].cells, vec![(0, 2)]);
+        assert_eq!(regions[1].values(&grid).collect::<String>(), "bbb");
+        assert_eq!(regions.label(2, 0), Some(1));
+        assert_eq!(regions.label(0, 0), None);
+        assert_eq!(regions.at(0, 2).map(|region| region.label), Some(0));
+        assert!(regions[1].bounds.contains(1, 0));
+        assert!(!regions[1].bounds.contains(0, 1));
+
+        let diagonal = grid.regions(Kind::Moore, |c| *c == 'b');
+        assert_eq!(diagonal.len(), 1);
+        assert_eq!((diagonal[0].bounds.top, diagonal[0].bounds.right), (0, 2));
+
+        let letters = grid.regions(Kind::VonNeumann, |c| *c != '.');
+        assert_eq!(
+            letters
+                .iter()
+                .map(|region| region.len())
+                .collect::<Vec<_>>(),
+            vec![6, 3]
+        );
+        assert_eq!(letters[0].values(&grid).collect::<String>(), "aabbbb");
+    }
+}
@@ -0,0 +1,306 @@
+use std::{
+    env,
+    fmt::{Display, Write},
+    io::{self, IsTerminal},
+};
+
+use crate::grid::Grid;
+
+/// Colors available to highlight cells with
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn ansi_code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+
+    fn css(self) -> &'static str {
+        match self {
+            Color::Red => "#e57373",
+            Color::Green => "#81c784",
+            Color::Yellow => "#ffd54f",
+            Color::Blue => "#64b5f6",
+            Color::Magenta => "#ba68c8",
+            Color::Cyan => "#4dd0e1",
+        }
+    }
+
+    /// Marks the cells of this color in plain text
+    fn letter(self) -> char {
+        match self {
+            Color::Red => 'R',
+            Color::Green => 'G',
+            Color::Yellow => 'Y',
+            Color::Blue => 'B',
+            Color::Magenta => 'M',
+            Color::Cyan => 'C',
+        }
+    }
+}
+
+/// Draws a grid with some of its cells highlighted
+///
+/// Each cell is drawn with its [`Display`] implementation, which should be a
+/// single character wide to keep the columns aligned.
+pub struct Renderer<'a, T> {
+    grid: &'a Grid<T>,
+    highlights: Grid<Option<Color>>,
+}
+
+const CELL_WIDTH: usize = 10;
+const CELL_HEIGHT: usize = 18;
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl<'a, T: Display> Renderer<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        Self {
+            grid,
+            highlights: grid.map(|_| None),
+        }
+    }
+
+    /// Highlight a single cell, replacing any earlier highlight
+    ///
+    /// Cells outside of the grid are ignored.
+    pub fn highlight(&mut self, row_idx: usize, col_idx: usize, color: Color) -> &mut Self {
+        if let Some(highlight) = self.highlights.get_mut(row_idx, col_idx) {
+            *highlight = Some(color);
+        }
+        self
+    }
+
+    /// Highlight every `(row, column)` position in `positions`
+    pub fn highlight_all(
+        &mut self,
+        positions: impl IntoIterator<Item = (usize, usize)>,
+        color: Color,
+    ) -> &mut Self {
+        for (row_idx, col_idx) in positions {
+            self.highlight(row_idx, col_idx, color);
+        }
+        self
+    }
+
+    pub fn highlighted(&self, row_idx: usize, col_idx: usize) -> Option<Color> {
+        self.highlights.get(row_idx, col_idx).copied().flatten()
+    }
+
+    /// Each row with its highlights, which are `None` for cells that are not highlighted
+    fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = (&T, Option<Color>)>> {
+        self.grid
+            .rows()
+            .zip(self.highlights.rows())
+            .map(|(values, highlights)| values.iter().zip(highlights.iter().copied()))
+    }
+
+    /// Color the highlighted cells with ANSI escape codes
+    pub fn ansi(&self) -> String {
+        let mut output = String::new();
+
+        for row in self.rows() {
+            for (value, highlight) in row {
+                match highlight {
+                    Some(color) => write!(output, "\x1b[1;{}m{value}\x1b[0m", color.ansi_code()),
+                    None => write!(output, "{value}"),
+                }
+                .expect("writing to a string should not fail");
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// Draw the grid without any escape codes, marking the highlighted cells of
+    /// each row on the line below it with the first letter of their color
+    pub fn plain(&self) -> String {
+        let mut output = String::new();
+
+        for row in self.rows() {
+            let (values, marks): (String, String) = row
+                .map(|(value, highlight)| {
+                    let mark = highlight.map_or(' ', Color::letter);
+                    (value.to_string(), mark.to_string())
+                })
+                .unzip();
+
+            output.push_str(&values);
+            output.push('\n');
+
+            if !marks.trim().is_empty() {
+                output.push_str(marks.trim_end());
+                output.push('\n');
+            }
+        }
+
+        output
+    }
+
+    /// Use ANSI colors when printing to a terminal that allows them, otherwise plain text
+    ///
+    /// Colors are disabled by setting the `NO_COLOR` environment variable.
+    pub fn terminal(&self) -> String {
+        if io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none() {
+            self.ansi()
+        } else {
+            self.plain()
+        }
+    }
+
+    /// Draw the grid as a standalone SVG image with highlighted cell backgrounds
+    pub fn svg(&self) -> String {
+        let (width, height) = (
+            self.grid.width() * CELL_WIDTH,
+            self.grid.height() * CELL_HEIGHT,
+        );
+        let mut output = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             font-family=\"monospace\" font-size=\"14\" text-anchor=\"middle\">\n"
+        );
+
+        for (row_idx, row) in self.rows().enumerate() {
+            let y = row_idx * CELL_HEIGHT;
+
+            for (col_idx, (value, highlight)) in row.enumerate() {
+                let x = col_idx * CELL_WIDTH;
+
+                if let Some(color) = highlight {
+                    writeln!(
+                        output,
+                        "  <rect x=\"{x}\" y=\"{y}\" width=\"{CELL_WIDTH}\" height=\"{CELL_HEIGHT}\" fill=\"{}\"/>",
+                        color.css()
+                    )
+                    .expect("writing to a string should not fail");
+                }
+
+                writeln!(
+                    output,
+                    "  <text x=\"{}\" y=\"{}\">{}</text>",
+                    x + CELL_WIDTH / 2,
+                    y + CELL_HEIGHT - 5,
+                    escape(&value.to_string())
+                )
+                .expect("writing to a string should not fail");
+            }
+        }
+
+        output.push_str("</svg>\n");
+        output
+    }
+
+    /// Draw the grid as an HTML `<pre>` block with highlighted cell backgrounds
+    pub fn html(&self) -> String {
+        let mut output = String::from("<pre>\n");
+
+        for row in self.rows() {
+            for (value, highlight) in row {
+                let value = escape(&value.to_string());
+                match highlight {
+                    Some(color) => write!(
+                        output,
+                        "<span style=\"background-color: {}\">{value}</span>",
+                        color.css()
+                    ),
+                    None => write!(output, "{value}"),
+                }
+                .expect("writing to a string should not fail");
+            }
+            output.push('\n');
+        }
+
+        output.push_str("</pre>\n");
+        output
+    }
+}
+
+impl<T: Display> Grid<T> {
+    /// Start drawing the grid, without any highlighted cells
+    pub fn renderer(&self) -> Renderer<'_, T> {
+        Renderer::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::render::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(
+            "1.<
+            .*2",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn ansi() {
+        let grid = grid();
+        let mut renderer = grid.renderer();
+        renderer
+            .highlight(0, 0, Color::Red)
+            .highlight_all([(1, 1), (1, 2)], Color::Green)
+            .highlight(1, 1, Color::Yellow)
+            .highlight(5, 5, Color::Blue);
+
+        assert_eq!(
+            renderer.ansi(),
+            "\x1b[1;31m1\x1b[0m.<\n.\x1b[1;33m*\x1b[0m\x1b[1;32m2\x1b[0m\n"
+        );
+        assert_eq!(renderer.highlighted(1, 1), Some(Color::Yellow));
+        assert_eq!(renderer.highlighted(0, 1), None);
+    }
+
+    #[test]
+    fn plain() {
+        let grid = grid();
+        let mut renderer = grid.renderer();
+        renderer.highlight_all([(1, 1), (1, 2)], Color::Green);
+
+        assert_eq!(renderer.plain(), "1.<\n.*2\n GG\n");
+        assert_eq!(grid.renderer().plain(), "1.<\n.*2\n");
+    }
+
+    #[test]
+    fn exports() {
+        let grid = grid();
+        let mut renderer = grid.renderer();
+        renderer.highlight(1, 2, Color::Cyan);
+
+        assert_eq!(
+            renderer.html(),
+            "<pre>\n1.&lt;\n.*<span style=\"background-color: #4dd0e1\">2</span>\n</pre>\n"
+        );
+
+        let svg = renderer.svg();
+        assert!(
+            svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"36\"")
+        );
+        assert!(
+            svg.contains("<rect x=\"20\" y=\"18\" width=\"10\" height=\"18\" fill=\"#4dd0e1\"/>")
+        );
+        assert!(svg.contains("<text x=\"25\" y=\"13\">&lt;</text>"));
+        assert_eq!(svg.matches("<text").count(), 6);
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -0,0 +1,112 @@
+use common::search::Graph;
+
+use crate::{cell::Cell, grid::Grid, neighborhood::Kind};
+
+/// A grid viewed as a graph between the `(row, column)` positions of its cells
+///
+/// Each step goes to a neighbor of the given kind and costs whatever `cost`
+/// returns for it, or is not allowed when it returns `None`.
+pub struct GridGraph<'a, T, F> {
+    grid: &'a Grid<T>,
+    kind: Kind<'a>,
+    cost: F,
+}
+
+impl<T> Grid<T> {
+    pub fn graph<'a, F>(&'a self, kind: Kind<'a>, cost: F) -> GridGraph<'a, T, F>
+    where
+        F: Fn(&Cell<'a, T>, &Cell<'a, T>) -> Option<u64>,
+    {
+        GridGraph {
+            grid: self,
+            kind,
+            cost,
+        }
+    }
+}
+
+impl<'a, T, F> Graph for GridGraph<'a, T, F>
+where
+    F: Fn(&Cell<'a, T>, &Cell<'a, T>) -> Option<u64>,
+{
+    type Node = (usize, usize);
+
+    fn neighbors(
+        &self,
+        &(row_idx, col_idx): &(usize, usize),
+    ) -> impl Iterator<Item = ((usize, usize), u64)> {
+        self.grid
+            .cell(row_idx, col_idx)
+            .into_iter()
+            .flat_map(move |cell| {
+                cell.neighbors(self.kind).filter_map(move |neighbor| {
+                    let cost = (self.cost)(&cell, &neighbor)?;
+                    Some(((neighbor.row_idx, neighbor.col_idx), cost))
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::search::{astar, bfs, dijkstra};
+
+    use crate::search::*;
+
+    fn maze() -> Grid<char> {
+        Grid::parse(
+            "S.#...
+            .##.#.
+            ...1#E
+            .#.9..",
+        )
+        .unwrap()
+    }
+
+    /// Step onto anything but walls, paying the digit on the cell or 1
+    fn cost(_: &Cell<char>, to: &Cell<char>) -> Option<u64> {
+        match to.value {
+            '#' => None,
+            c => Some(c.to_digit(10).map_or(1, u64::from)),
+        }
+    }
+
+    #[test]
+    fn fewest_steps() {
+        let maze = maze();
+        let graph = maze.graph(Kind::VonNeumann, cost);
+        let path = bfs(&graph, (0, 0), |&pos| maze[pos] == 'E').unwrap();
+
+        assert_eq!(path.steps(), 9);
+        assert_eq!(path.goal(), &(2, 5));
+        assert!(path.nodes.windows(2).all(|step| {
+            let ((r1, c1), (r2, c2)) = (step[0], step[1]);
+            r1.abs_diff(r2) + c1.abs_diff(c2) == 1 && maze[step[1]] != '#'
+        }));
+
+        let diagonal = maze.graph(Kind::Moore, cost);
+        assert_eq!(
+            bfs(&diagonal, (0, 0), |&pos| maze[pos] == 'E')
+                .unwrap()
+                .steps(),
+            6
+        );
+    }
+
+    #[test]
+    fn cheapest_steps() {
+        let maze = maze();
+        let graph = maze.graph(Kind::VonNeumann, cost);
+        let path = dijkstra(&graph, (0, 0), |&pos| maze[pos] == 'E').unwrap();
+
+        assert_eq!(path.cost, 11);
+        assert!(!path.nodes.contains(&(3, 3)));
+
+        let manhattan = |&(row, col): &(usize, usize)| (row.abs_diff(2) + col.abs_diff(5)) as u64;
+        let guided = astar(&graph, (0, 0), |&pos| maze[pos] == 'E', manhattan).unwrap();
+        assert_eq!(guided.cost, path.cost);
+
+        let walled = maze.graph(Kind::VonNeumann, |_, to| (to.value == &'.').then_some(1));
+        assert_eq!(dijkstra(&walled, (0, 0), |&pos| maze[pos] == 'E'), None);
+    }
+}
@@ -0,0 +1,345 @@
+use std::{
+    collections::{btree_map, BTreeMap},
+    fmt::{self, Display},
+};
+
+use crate::{grid::Grid, neighborhood::Kind, region::BoundingBox};
+
+/// A grid that only stores the positions holding a value, which may be anywhere
+///
+/// Positions are signed, so the grid can grow in every direction. Cells are
+/// visited in row-major order, like the cells of a [`Grid`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SparseGrid<T> {
+    values: BTreeMap<(i64, i64), T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            values: BTreeMap::new(),
+        }
+    }
+}
+
+/// A value within a sparse grid, along with its position
+#[derive(PartialEq, Debug)]
+pub struct SparseCell<'a, T> {
+    pub grid: &'a SparseGrid<T>,
+    pub row_idx: i64,
+    pub col_idx: i64,
+    pub value: &'a T,
+}
+
+impl<'a, T> Clone for SparseCell<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for SparseCell<'a, T> {}
+
+impl<'a, T> SparseCell<'a, T> {
+    /// The surrounding cells of the given kind that hold a value
+    pub fn neighbors(&self, kind: Kind<'a>) -> SparseNeighborhood<'a, T> {
+        SparseNeighborhood {
+            grid: self.grid,
+            row_idx: self.row_idx,
+            col_idx: self.col_idx,
+            offsets: kind.offsets().iter(),
+        }
+    }
+}
+
+/// An iterator that goes through the surrounding cells of a sparse cell,
+/// skipping the positions without a value
+#[derive(Clone, Debug)]
+pub struct SparseNeighborhood<'a, T> {
+    grid: &'a SparseGrid<T>,
+    row_idx: i64,
+    col_idx: i64,
+    offsets: std::slice::Iter<'a, (isize, isize)>,
+}
+
+impl<'a, T> Iterator for SparseNeighborhood<'a, T> {
+    type Item = SparseCell<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.offsets.find_map(|&(row_offset, col_offset)| {
+            // Neighbors past the edge of the coordinates can never hold a value
+            let row_idx = self.row_idx.checked_add(row_offset as i64)?;
+            let col_idx = self.col_idx.checked_add(col_offset as i64)?;
+
+            self.grid.cell(row_idx, col_idx)
+        })
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of positions holding a value
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Store `value` at a position, returning the value it replaced
+    pub fn insert(&mut self, row_idx: i64, col_idx: i64, value: T) -> Option<T> {
+        self.values.insert((row_idx, col_idx), value)
+    }
+
+    pub fn remove(&mut self, row_idx: i64, col_idx: i64) -> Option<T> {
+        self.values.remove(&(row_idx, col_idx))
+    }
+
+    pub fn get(&self, row_idx: i64, col_idx: i64) -> Option<&T> {
+        self.values.get(&(row_idx, col_idx))
+    }
+
+    pub fn get_mut(&mut self, row_idx: i64, col_idx: i64) -> Option<&mut T> {
+        self.values.get_mut(&(row_idx, col_idx))
+    }
+
+    /// The value at a position, storing the result of `default` there first if it is empty
+    pub fn get_or_insert_with(
+        &mut self,
+        row_idx: i64,
+        col_idx: i64,
+        default: impl FnOnce() -> T,
+    ) -> &mut T {
+        self.values
+            .entry((row_idx, col_idx))
+            .or_insert_with(default)
+    }
+
+    pub fn contains(&self, row_idx: i64, col_idx: i64) -> bool {
+        self.values.contains_key(&(row_idx, col_idx))
+    }
+
+    pub fn cell(&self, row_idx: i64, col_idx: i64) -> Option<SparseCell<'_, T>> {
+        let value = self.get(row_idx, col_idx)?;
+
+        Some(SparseCell {
+            grid: self,
+            row_idx,
+            col_idx,
+            value,
+        })
+    }
+
+    /// Iterate over the cells holding a value, in row-major order
+    pub fn cells(&self) -> impl DoubleEndedIterator<Item = SparseCell<'_, T>> + '_ {
+        self.values
+            .iter()
+            .map(|(&(row_idx, col_idx), value)| SparseCell {
+                grid: self,
+                row_idx,
+                col_idx,
+                value,
+            })
+    }
+
+    /// The smallest rectangle containing every value, or `None` if the grid is empty
+    pub fn bounds(&self) -> Option<BoundingBox<i64>> {
+        let mut positions = self.values.keys().copied();
+        let mut bounds = BoundingBox::new(positions.next()?);
+        for position in positions {
+            bounds.include(position);
+        }
+
+        Some(bounds)
+    }
+
+    /// Copy the values within the bounds into a dense grid, indexed from `(0, 0)`,
+    /// with `fill` in the empty positions
+    pub fn to_grid(&self, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let Some(bounds) = self.bounds() else {
+            return Grid::from_vec(0, Vec::new()).expect("an empty grid has no rows");
+        };
+
+        let values = (bounds.top..=bounds.bottom)
+            .flat_map(|row_idx| (bounds.left..=bounds.right).map(move |col_idx| (row_idx, col_idx)))
+            .map(|(row_idx, col_idx)| self.get(row_idx, col_idx).unwrap_or(&fill).clone())
+            .collect();
+
+        Grid::from_vec(bounds.width(), values).expect("the bounds should fill every row")
+    }
+}
+
+impl<T: Clone> From<&Grid<T>> for SparseGrid<T> {
+    /// Copy every value of a dense grid to the same position
+    fn from(grid: &Grid<T>) -> Self {
+        grid.cells()
+            .map(|cell| {
+                let position = (cell.row_idx as i64, cell.col_idx as i64);
+                (position, cell.value.clone())
+            })
+            .collect()
+    }
+}
+
+impl<T> FromIterator<((i64, i64), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((i64, i64), T)>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> IntoIterator for SparseGrid<T> {
+    type Item = ((i64, i64), T);
+    type IntoIter = btree_map::IntoIter<(i64, i64), T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    /// Draw every row within the bounds, with a `.` in the empty positions
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(bounds) = self.bounds() else {
+            return Ok(());
+        };
+
+        for row_idx in bounds.top..=bounds.bottom {
+            for col_idx in bounds.left..=bounds.right {
+                match self.get(row_idx, col_idx) {
+                    Some(value) => write!(f, "{value}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sparse::*;
+
+    fn grid() -> SparseGrid<char> {
+        SparseGrid::from_iter([((-1, 0), 'a'), ((0, -2), 'b'), ((0, 1), 'c'), ((2, 0), 'd')])
+    }
+
+    #[test]
+    fn growing() {
+        let mut grid = grid();
+
+        assert_eq!(grid.len(), 4);
+        assert_eq!(grid.get(0, -2), Some(&'b'));
+        assert_eq!(grid.get(0, 0), None);
+        assert_eq!(
+            grid.bounds(),
+            Some(BoundingBox {
+                top: -1,
+                left: -2,
+                bottom: 2,
+                right: 1
+            })
+        );
+
+        *grid.get_or_insert_with(-3, 5, || 'e') = 'f';
+        assert_eq!(grid.get(-3, 5), Some(&'f'));
+        assert_eq!(grid.bounds().map(|bounds| bounds.width()), Some(8));
+        assert_eq!(grid.remove(-3, 5), Some('f'));
+        assert_eq!(grid.bounds().map(|bounds| bounds.height()), Some(4));
+
+        assert_eq!(SparseGrid::<char>::new().bounds(), None);
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = grid();
+        let cell = SparseCell {
+            grid: &grid,
+            row_idx: 0,
+            col_idx: 0,
+            value: &'x',
+        };
+
+        assert_eq!(
+            cell.neighbors(Kind::VonNeumann)
+                .map(|cell| *cell.value)
+                .collect::<String>(),
+            "ac"
+        );
+        assert_eq!(
+            grid.cells().map(|cell| *cell.value).collect::<String>(),
+            "abcd"
+        );
+
+        let b = grid.cell(0, -2).unwrap();
+        assert_eq!(b.neighbors(Kind::Moore).count(), 0);
+    }
+
+    #[test]
+    fn neighbors_at_the_edge_of_the_coordinates() {
+        let grid = SparseGrid::from_iter([
+            ((i64::MAX, i64::MAX), 'a'),
+            ((i64::MAX - 1, i64::MAX), 'b'),
+            ((i64::MIN, i64::MIN), 'c'),
+            ((i64::MIN, i64::MIN + 1), 'd'),
+        ]);
+
+        let bounds = grid.bounds().unwrap();
+        assert_eq!((bounds.width(), bounds.height()), (usize::MAX, usize::MAX));
+        let inverted: BoundingBox<i64> = BoundingBox {
+            top: 1,
+            left: 1,
+            bottom: -1,
+            right: 0,
+        };
+        assert_eq!((inverted.width(), inverted.height()), (0, 0));
+
+        let corner = grid.cell(i64::MAX, i64::MAX).unwrap();
+        assert_eq!(
+            corner
+                .neighbors(Kind::Moore)
+                .map(|cell| *cell.value)
+                .collect::<String>(),
+            "b"
+        );
+
+        let corner = grid.cell(i64::MIN, i64::MIN).unwrap();
+        assert_eq!(
+            corner
+                .neighbors(Kind::Moore)
+                .map(|cell| *cell.value)
+                .collect::<String>(),
+            "d"
+        );
+    }
+
+    #[test]
+    fn rendering() {
+        let grid = grid();
+
+        assert_eq!(grid.to_string(), "..a.\nb..c\n....\n..d.\n");
+        assert_eq!(
+            grid.to_grid(' ').rows().nth(1),
+            Some(&['b', ' ', ' ', 'c'][..])
+        );
+
+        let dense = Grid::parse(
+            "ab
+            cd",
+        )
+        .unwrap();
+        let sparse = SparseGrid::from(&dense);
+        assert_eq!(sparse.get(1, 0), Some(&'c'));
+        assert_eq!(sparse.to_grid('.'), dense);
+        assert_eq!(SparseGrid::<char>::new().to_string(), "");
+    }
+}
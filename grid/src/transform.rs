@@ -0,0 +1,380 @@
+use crate::{
+    cell::{Cell, Cells, Order},
+    grid::Grid,
+    region::BoundingBox,
+};
+
+impl<T: Clone> Grid<T> {
+    /// Build a `width` by `height` grid, taking each value from the position of
+    /// this grid that `source` maps the new position to
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Grid<T> {
+        let values = (0..height)
+            .flat_map(|row_idx| (0..width).map(move |col_idx| (row_idx, col_idx)))
+            .map(|(row_idx, col_idx)| self[source(row_idx, col_idx)].clone())
+            .collect();
+
+        Grid::from_vec(width, values).expect("remapped values should fill every row")
+    }
+
+    /// Swap rows and columns, mirroring the grid along its main diagonal
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height(), self.width(), |row_idx, col_idx| {
+            (col_idx, row_idx)
+        })
+    }
+
+    /// Rotate by 90° clockwise, so the left column becomes the top row
+    pub fn rotate_cw(&self) -> Grid<T> {
+        let height = self.height();
+        self.remap(height, self.width(), |row_idx, col_idx| {
+            (height - 1 - col_idx, row_idx)
+        })
+    }
+
+    /// Rotate by 90° counterclockwise, so the right column becomes the top row
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        let width = self.width();
+        self.remap(self.height(), width, |row_idx, col_idx| {
+            (col_idx, width - 1 - row_idx)
+        })
+    }
+
+    /// Rotate by 180°, reversing both rows and columns
+    pub fn rotate_180(&self) -> Grid<T> {
+        let (width, height) = (self.width(), self.height());
+        self.remap(width, height, |row_idx, col_idx| {
+            (height - 1 - row_idx, width - 1 - col_idx)
+        })
+    }
+
+    /// Mirror left to right, reversing every row
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width();
+        self.remap(width, self.height(), |row_idx, col_idx| {
+            (row_idx, width - 1 - col_idx)
+        })
+    }
+
+    /// Mirror top to bottom, reversing every column
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height();
+        self.remap(self.width(), height, |row_idx, col_idx| {
+            (height - 1 - row_idx, col_idx)
+        })
+    }
+
+    /// Copy the part of the grid within `bounds` into a new grid, ignoring any
+    /// part of `bounds` outside of the grid
+    ///
+    /// Unlike a [`View`], the copy is indexed from `(0, 0)`.
+    pub fn crop(&self, bounds: &BoundingBox) -> Grid<T> {
+        self.view(bounds).to_grid()
+    }
+}
+
+/// A borrowed rectangle of a grid, addressed with the coordinates of the full grid
+#[derive(PartialEq, Debug)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    top: usize,
+    left: usize,
+    height: usize,
+    width: usize,
+}
+
+impl<'a, T> Clone for View<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for View<'a, T> {}
+
+impl<T> Grid<T> {
+    /// View the part of the grid within `bounds`, ignoring any part outside of the grid
+    pub fn view(&self, bounds: &BoundingBox) -> View<'_, T> {
+        let bottom = bounds.bottom.saturating_add(1).min(self.height());
+        let right = bounds.right.saturating_add(1).min(self.width());
+
+        View::new(
+            self,
+            (bounds.top, bounds.left),
+            (
+                bottom.saturating_sub(bounds.top),
+                right.saturating_sub(bounds.left),
+            ),
+        )
+    }
+}
+
+impl<'a, T> View<'a, T> {
+    fn new(
+        grid: &'a Grid<T>,
+        (top, left): (usize, usize),
+        (height, width): (usize, usize),
+    ) -> Self {
+        // Keep empty views from pointing at rows or columns outside of the grid
+        let (height, width) = if height == 0 || width == 0 {
+            (0, 0)
+        } else {
+            (height, width)
+        };
+
+        Self {
+            grid,
+            top,
+            left,
+            height,
+            width,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// The parent grid's coordinates of the view, or `None` if it is empty
+    pub fn bounds(&self) -> Option<BoundingBox> {
+        (!self.is_empty()).then(|| BoundingBox {
+            top: self.top,
+            left: self.left,
+            bottom: self.top + self.height - 1,
+            right: self.left + self.width - 1,
+        })
+    }
+
+    pub fn contains(&self, row_idx: usize, col_idx: usize) -> bool {
+        (self.top..self.top + self.height).contains(&row_idx)
+            && (self.left..self.left + self.width).contains(&col_idx)
+    }
+
+    /// The value at a position of the parent grid, if it is within the view
+    pub fn get(&self, row_idx: usize, col_idx: usize) -> Option<&'a T> {
+        if !self.contains(row_idx, col_idx) {
+            return None;
+        }
+
+        self.grid.get(row_idx, col_idx)
+    }
+
+    /// The cell at a position of the parent grid, if it is within the view
+    ///
+    /// Its neighbors may lie outside of the view.
+    pub fn cell(&self, row_idx: usize, col_idx: usize) -> Option<Cell<'a, T>> {
+        if !self.contains(row_idx, col_idx) {
+            return None;
+        }
+
+        self.grid.cell(row_idx, col_idx)
+    }
+
+    pub fn cells(&self) -> Cells<'a, T> {
+        self.cells_row_major()
+    }
+
+    pub fn cells_row_major(&self) -> Cells<'a, T> {
+        self.cells_in(Order::RowMajor)
+    }
+
+    pub fn cells_col_major(&self) -> Cells<'a, T> {
+        self.cells_in(Order::ColumnMajor)
+    }
+
+    fn cells_in(&self, order: Order) -> Cells<'a, T> {
+        Cells::within(
+            self.grid,
+            (self.top, self.left),
+            (self.height, self.width),
+            order,
+        )
+    }
+
+    /// Iterate over the part of each row within the view, from top to bottom
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &'a [T]> + 'a {
+        let (left, width) = (self.left, self.width);
+        self.grid
+            .rows()
+            .skip(self.top)
+            .take(self.height)
+            .map(move |row| &row[left..left + width])
+    }
+
+    /// A smaller view within this one, with `bounds` in the parent grid's coordinates
+    pub fn view(&self, bounds: &BoundingBox) -> View<'a, T> {
+        let top = bounds.top.max(self.top);
+        let left = bounds.left.max(self.left);
+        let bottom = bounds.bottom.saturating_add(1).min(self.top + self.height);
+        let right = bounds.right.saturating_add(1).min(self.left + self.width);
+
+        View::new(
+            self.grid,
+            (top, left),
+            (bottom.saturating_sub(top), right.saturating_sub(left)),
+        )
+    }
+
+    /// Copy the view into a new grid, indexed from `(0, 0)`
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_rows(self.rows().map(|row| row.to_vec()))
+            .expect("rows of a view should all have the same length")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::transform::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(
+            "abc
+            def",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn rotations_and_flips() {
+        let grid = grid();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_180().to_string(), "fed\ncba\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.rotate_180());
+        assert_eq!(grid.transpose().transpose(), grid);
+        assert_eq!(grid.flip_horizontal().flip_vertical(), grid.rotate_180());
+
+        let empty = Grid::<char>::parse("").unwrap();
+        assert_eq!(empty.rotate_cw(), empty);
+    }
+
+    #[test]
+    fn cropping() {
+        let grid = grid();
+        let bounds = |top, left, bottom, right| BoundingBox {
+            top,
+            left,
+            bottom,
+            right,
+        };
+
+        assert_eq!(grid.crop(&bounds(0, 1, 1, 2)).to_string(), "bc\nef\n");
+        assert_eq!(grid.crop(&bounds(1, 2, 5, 5)).to_string(), "f\n");
+        assert!(grid.crop(&bounds(2, 0, 3, 3)).is_empty());
+        assert_eq!(
+            grid.crop(&bounds(1, 0, usize::MAX, usize::MAX)).to_string(),
+            "def\n"
+        );
+    }
+
+    #[test]
+    fn inverted_bounds() {
+        let grid = grid();
+        let inverted: BoundingBox = BoundingBox {
+            top: 1,
+            left: 2,
+            bottom: 0,
+            right: 0,
+        };
+
+        assert_eq!((inverted.height(), inverted.width()), (0, 0));
+        assert!(grid.view(&inverted).is_empty());
+        assert!(grid.crop(&inverted).is_empty());
+        assert!(grid
+            .view(&BoundingBox {
+                top: 0,
+                left: 0,
+                bottom: 1,
+                right: 2
+            })
+            .view(&inverted)
+            .is_empty());
+        assert_eq!(grid.cells_within(&inverted).count(), 0);
+    }
+
+    #[test]
+    fn views_keep_parent_coordinates() {
+        let grid = Grid::parse(
+            "abcd
+            efgh
+            ijkl",
+        )
+        .unwrap();
+        let view = grid.view(&BoundingBox {
+            top: 1,
+            left: 1,
+            bottom: 2,
+            right: 9,
+        });
+
+        assert_eq!((view.height(), view.width()), (2, 3));
+        assert_eq!(view.get(1, 1), Some(&'f'));
+        assert_eq!(view.get(0, 1), None);
+        assert_eq!(view.get(1, 0), None);
+        assert_eq!(
+            view.bounds(),
+            Some(BoundingBox {
+                top: 1,
+                left: 1,
+                bottom: 2,
+                right: 3
+            })
+        );
+
+        let cell = view.cells().next_back().unwrap();
+        assert_eq!((cell.row_idx, cell.col_idx, *cell.value), (2, 3, 'l'));
+        assert_eq!(
+            view.cells_col_major()
+                .map(|cell| *cell.value)
+                .collect::<String>(),
+            "fjgkhl"
+        );
+        assert_eq!(
+            view.rows().collect::<Vec<_>>(),
+            vec![&['f', 'g', 'h'], &['j', 'k', 'l']]
+        );
+        assert_eq!(view.to_grid().to_string(), "fgh\njkl\n");
+
+        let inner = view.view(&BoundingBox {
+            top: 0,
+            left: 2,
+            bottom: 1,
+            right: 2,
+        });
+        assert_eq!(
+            inner.cells().map(|cell| *cell.value).collect::<String>(),
+            "g"
+        );
+        assert_eq!(inner.get(1, 2), Some(&'g'));
+
+        let outside = grid.view(&BoundingBox {
+            top: 5,
+            left: 5,
+            bottom: 6,
+            right: 6,
+        });
+        assert!(outside.is_empty());
+        assert_eq!(outside.bounds(), None);
+        assert_eq!(outside.cells().count(), 0);
+    }
+}